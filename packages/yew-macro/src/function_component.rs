@@ -62,12 +62,14 @@ impl Parse for FunctionComponent {
                     ReturnType::Default => {
                         return Err(syn::Error::new_spanned(
                             sig,
-                            "function components must return `yew::Html`",
+                            "function components must return `yew::Html` or `yew::HtmlResult`",
                         ))
                     }
                     ReturnType::Type(_, ty) => ty,
                 };
 
+                crate::hook::check_hook_calls(&block, is_html_result(&return_type))?;
+
                 let mut inputs = sig.inputs.into_iter();
                 let arg: FnArg = inputs
                     .next()
@@ -181,7 +183,11 @@ pub fn function_component_impl(
         ));
     }
 
-    let ret_type = quote_spanned!(return_type.span()=> ::yew::html::Html);
+    // The body is wrapped in a closure returning the declared type, so components returning
+    // `HtmlResult` can use `?` to suspend.
+    let body = quote_spanned! {return_type.span()=>
+        ::yew::functional::render_suspendable(|| -> #return_type #block)
+    };
 
     let phantom_generics = generics
         .type_params()
@@ -199,8 +205,8 @@ pub fn function_component_impl(
         impl #impl_generics ::yew::functional::FunctionProvider for #function_name #ty_generics #where_clause {
             type TProps = #props_type;
 
-            fn run(#arg) -> #ret_type {
-                #block
+            fn run(#arg) -> ::yew::html::Html {
+                #body
             }
         }
 
@@ -211,3 +217,15 @@ pub fn function_component_impl(
 
     Ok(quoted)
 }
+
+/// Returns whether `ty` names `HtmlResult`, whose `?` suspends the component.
+fn is_html_result(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "HtmlResult"),
+        _ => false,
+    }
+}
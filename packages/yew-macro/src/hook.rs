@@ -0,0 +1,186 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream};
+use syn::visit::{self, Visit};
+use syn::{BinOp, Block, Expr, ExprPath, Item, ItemFn};
+
+pub struct HookFn {
+    func: ItemFn,
+}
+
+impl Parse for HookFn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let parsed: Item = input.parse()?;
+
+        match parsed {
+            Item::Fn(func) => {
+                if !func.sig.ident.to_string().starts_with("use_") {
+                    return Err(syn::Error::new_spanned(
+                        &func.sig.ident,
+                        "hooks must have a name starting with `use_`",
+                    ));
+                }
+
+                if func.sig.asyncness.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        func.sig.asyncness,
+                        "hooks can't be async",
+                    ));
+                }
+
+                check_hook_calls(&func.block, false)?;
+
+                Ok(Self { func })
+            }
+            item => Err(syn::Error::new_spanned(
+                item,
+                "`hook` attribute can only be applied to functions",
+            )),
+        }
+    }
+}
+
+pub fn hook_impl(hook: HookFn) -> TokenStream {
+    hook.func.into_token_stream()
+}
+
+/// Returns an error for every hook call in `block` which is not run on every call of the
+/// function, or not in the same order.
+///
+/// A hook call is a call of a function whose name starts with `use_`. Hook calls which may follow
+/// an early `return` or `?` are rejected as well, unless `suspendable` is set for a function
+/// component returning `HtmlResult`. Its `?` suspends the component, which calls the hooks after it
+/// in order when it is rendered again. Calls in macro invocations and nested items are not checked.
+pub fn check_hook_calls(block: &Block, suspendable: bool) -> syn::Result<()> {
+    let mut checker = HookCallChecker {
+        suspendable,
+        ..HookCallChecker::default()
+    };
+    checker.visit_block(block);
+    crate::join_errors(checker.errors.into_iter())
+}
+
+#[derive(Default)]
+struct HookCallChecker {
+    /// Describes the innermost expression making the current position conditional
+    conditional: Option<&'static str>,
+    /// Whether a `return` or `?` of the function may have been evaluated before the current
+    /// position
+    returned: bool,
+    /// Whether a `?` suspends the function component instead of returning early
+    suspendable: bool,
+    errors: Vec<syn::Error>,
+}
+
+impl HookCallChecker {
+    fn visit_conditional(&mut self, context: &'static str, f: impl FnOnce(&mut Self)) {
+        let outer = self.conditional.replace(context);
+        f(self);
+        self.conditional = outer;
+    }
+
+    /// Visits the body of a closure or async block, which is not returned from by a `return` or
+    /// `?` inside it.
+    fn visit_nested_body(&mut self, context: &'static str, f: impl FnOnce(&mut Self)) {
+        let returned = self.returned;
+        self.visit_conditional(context, f);
+        self.returned = returned;
+    }
+}
+
+impl<'ast> Visit<'ast> for HookCallChecker {
+    fn visit_expr_call(&mut self, i: &'ast syn::ExprCall) {
+        if let Expr::Path(ExprPath { path, .. }) = &*i.func {
+            let is_hook = path.segments.last().map_or(false, |segment| {
+                segment.ident.to_string().starts_with("use_")
+            });
+            let position = match self.conditional {
+                Some(context) => Some(format!("in {}", context)),
+                None if self.returned => Some("after an early `return` or `?`".to_string()),
+                None => None,
+            };
+            if let (true, Some(position)) = (is_hook, position) {
+                self.errors.push(syn::Error::new_spanned(
+                    path,
+                    format!(
+                        "hooks can't be called {}, as they must be called in the same order on every render",
+                        position
+                    ),
+                ));
+            }
+        }
+
+        visit::visit_expr_call(self, i);
+    }
+
+    fn visit_expr_if(&mut self, i: &'ast syn::ExprIf) {
+        self.visit_expr(&i.cond);
+        self.visit_conditional("an `if` expression", |v| {
+            v.visit_block(&i.then_branch);
+            if let Some((_, else_branch)) = &i.else_branch {
+                v.visit_expr(else_branch);
+            }
+        });
+    }
+
+    fn visit_expr_match(&mut self, i: &'ast syn::ExprMatch) {
+        self.visit_expr(&i.expr);
+        self.visit_conditional("a `match` arm", |v| {
+            for arm in &i.arms {
+                v.visit_arm(arm);
+            }
+        });
+    }
+
+    fn visit_expr_binary(&mut self, i: &'ast syn::ExprBinary) {
+        match i.op {
+            BinOp::And(_) | BinOp::Or(_) => {
+                self.visit_expr(&i.left);
+                self.visit_conditional("the right-hand side of `&&` or `||`", |v| {
+                    v.visit_expr(&i.right)
+                });
+            }
+            _ => visit::visit_expr_binary(self, i),
+        }
+    }
+
+    fn visit_expr_for_loop(&mut self, i: &'ast syn::ExprForLoop) {
+        self.visit_expr(&i.expr);
+        self.visit_conditional("a loop", |v| v.visit_block(&i.body));
+    }
+
+    fn visit_expr_while(&mut self, i: &'ast syn::ExprWhile) {
+        self.visit_conditional("a loop", |v| {
+            v.visit_expr(&i.cond);
+            v.visit_block(&i.body);
+        });
+    }
+
+    fn visit_expr_loop(&mut self, i: &'ast syn::ExprLoop) {
+        self.visit_conditional("a loop", |v| v.visit_block(&i.body));
+    }
+
+    fn visit_expr_return(&mut self, i: &'ast syn::ExprReturn) {
+        visit::visit_expr_return(self, i);
+        self.returned = true;
+    }
+
+    fn visit_expr_try(&mut self, i: &'ast syn::ExprTry) {
+        visit::visit_expr_try(self, i);
+        if !self.suspendable {
+            self.returned = true;
+        }
+    }
+
+    fn visit_expr_closure(&mut self, i: &'ast syn::ExprClosure) {
+        self.visit_nested_body("a closure", |v| v.visit_expr(&i.body));
+    }
+
+    fn visit_expr_async(&mut self, i: &'ast syn::ExprAsync) {
+        self.visit_nested_body("an async block", |v| v.visit_block(&i.block));
+    }
+
+    fn visit_item(&mut self, _i: &'ast Item) {
+        // Nested items are not part of the body
+    }
+}
@@ -106,6 +106,7 @@ impl ToTokens for HtmlElement {
             node_ref,
             key,
             listeners,
+            custom_listeners,
         } = &props;
 
         // attributes with special treatment
@@ -268,7 +269,7 @@ impl ToTokens for HtmlElement {
             })
         };
 
-        let listeners = if listeners.is_empty() {
+        let listeners = if listeners.is_empty() && custom_listeners.is_empty() {
             quote! { ::std::vec![] }
         } else {
             let listeners_it = listeners.iter().map(|Prop { label, value, .. }| {
@@ -277,6 +278,14 @@ impl ToTokens for HtmlElement {
                     ::yew::html::#name::Wrapper::__macro_new(#value)
                 }
             });
+            let custom_listeners_it = custom_listeners.iter().map(|prop| {
+                let event_name = prop.custom_event.as_ref().map(HtmlDashedName::to_lit_str);
+                let value = &prop.value;
+                quote! {
+                    ::yew::html::CustomListener::__macro_new(#event_name, #value)
+                }
+            });
+            let listeners_it = listeners_it.chain(custom_listeners_it);
 
             quote! { ::std::vec![#(#listeners_it),*].into_iter().flatten().collect() }
         };
@@ -197,7 +197,12 @@ impl TryFrom<Props> for ComponentProps {
     fn try_from(props: Props) -> Result<Self, Self::Error> {
         props.check_no_duplicates()?;
         props.check_all(|prop| {
-            if !prop.label.extended.is_empty() {
+            if let Some(event) = &prop.custom_event {
+                Err(syn::Error::new_spanned(
+                    event,
+                    "custom event listeners can only be added to elements",
+                ))
+            } else if !prop.label.extended.is_empty() {
                 Err(syn::Error::new_spanned(
                     &prop.label,
                     "expected a valid Rust identifier",
@@ -20,6 +20,7 @@ impl ClassesForm {
 pub struct ElementProps {
     pub attributes: Vec<Prop>,
     pub listeners: Vec<Prop>,
+    pub custom_listeners: Vec<Prop>,
     pub classes: Option<ClassesForm>,
     pub booleans: Vec<Prop>,
     pub value: Option<Prop>,
@@ -34,6 +35,7 @@ impl Parse for ElementProps {
 
         let listeners =
             props.drain_filter(|prop| LISTENER_SET.contains(prop.label.to_string().as_str()));
+        let custom_listeners = props.drain_filter(|prop| prop.custom_event.is_some());
 
         // Multiple listener attributes are allowed, but no others
         props.check_no_duplicates()?;
@@ -53,6 +55,7 @@ impl Parse for ElementProps {
             attributes: props.prop_list.into_vec(),
             classes,
             listeners: listeners.into_vec(),
+            custom_listeners: custom_listeners.into_vec(),
             checked,
             booleans: booleans.into_vec(),
             value,
@@ -13,6 +13,8 @@ use syn::{
 
 pub struct Prop {
     pub label: HtmlDashedName,
+    /// The event name of a custom event listener `on:event-name`, whose label is `on`.
+    pub custom_event: Option<HtmlDashedName>,
     /// Punctuation between `label` and `value`.
     pub value: Expr,
 }
@@ -56,12 +58,23 @@ impl Prop {
             ));
         }?;
 
-        Ok(Self { label, value: expr })
+        Ok(Self {
+            label,
+            custom_event: None,
+            value: expr,
+        })
     }
 
-    /// Parse a prop of the form `label={value}`
+    /// Parse a prop of the form `label={value}` or `on:event-name={value}`
     fn parse_prop_assignment(input: ParseStream) -> syn::Result<Self> {
         let label = input.parse::<HtmlDashedName>()?;
+        let custom_event =
+            if label.extended.is_empty() && label.name == "on" && input.peek(Token![:]) {
+                input.parse::<Token![:]>()?;
+                Some(input.parse::<HtmlDashedName>()?)
+            } else {
+                None
+            };
         let equals = input.parse::<Token![=]>().map_err(|_| {
             syn::Error::new_spanned(
                 &label,
@@ -75,7 +88,11 @@ impl Prop {
             ));
         }
         let value = strip_braces(input.parse::<Expr>()?)?;
-        Ok(Self { label, value })
+        Ok(Self {
+            label,
+            custom_event,
+            value,
+        })
     }
 }
 
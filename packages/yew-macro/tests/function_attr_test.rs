@@ -2,6 +2,6 @@
 #[rustversion::attr(stable(1.51), test)]
 fn tests() {
     let t = trybuild::TestCases::new();
-    t.pass("tests/function_attr/*-pass.rs");
-    t.compile_fail("tests/function_attr/*-fail.rs");
+    t.pass("tests/function_component_attr/*-pass.rs");
+    t.compile_fail("tests/function_component_attr/*-fail.rs");
 }
@@ -1,5 +1,5 @@
 use yew::prelude::*;
-use yew_functional::function_component;
+use yew::function_component;
 
 #[derive(Clone, Properties, PartialEq)]
 struct Props {
@@ -0,0 +1,15 @@
+use yew::prelude::*;
+
+#[derive(Clone, Properties, PartialEq)]
+struct Props {
+    a: usize,
+}
+
+#[function_component(ByValue)]
+fn by_value(Props { a }: Props) -> Html {
+    html! {
+        <p>{ a }</p>
+    }
+}
+
+fn main() {}
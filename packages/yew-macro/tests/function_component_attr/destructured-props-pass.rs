@@ -0,0 +1,48 @@
+#[derive(Clone, ::yew::Properties, PartialEq)]
+struct Props {
+    a: usize,
+    b: String,
+}
+
+#[::yew::function_component(Comp)]
+fn comp(Props { a, b }: &Props) -> ::yew::Html {
+    ::yew::html! {
+        <p>{ a }{ b }</p>
+    }
+}
+
+#[::yew::function_component(CompWithRest)]
+fn comp_with_rest(&Props { a, .. }: &Props) -> ::yew::Html {
+    let a: usize = a;
+    ::yew::html! {
+        <p>{ a }</p>
+    }
+}
+
+#[derive(Clone, ::yew::Properties, PartialEq)]
+struct GenericProps<T: Clone + PartialEq + 'static> {
+    value: T,
+}
+
+#[::yew::function_component(GenericComp)]
+fn generic_comp<T: Clone + PartialEq + ::std::fmt::Display + 'static>(
+    GenericProps { value }: &GenericProps<T>,
+) -> ::yew::Html {
+    ::yew::html! {
+        <p>{ value }</p>
+    }
+}
+
+#[::yew::function_component(Unused)]
+fn unused(_: &Props) -> ::yew::Html {
+    ::yew::html! {}
+}
+
+fn compile_pass() {
+    ::yew::html! { <Comp a=1 b="b" /> };
+    ::yew::html! { <CompWithRest a=1 b="b" /> };
+    ::yew::html! { <GenericComp<usize> value=1 /> };
+    ::yew::html! { <Unused a=1 b="b" /> };
+}
+
+fn main() {}
@@ -3,7 +3,7 @@ struct Props {
     a: usize,
 }
 
-#[::yew_functional::function_component(Comp)]
+#[::yew::function_component(Comp)]
 fn comp<P>(_props: &P) -> ::yew::Html
 where
     P: ::yew::Properties + PartialEq,
@@ -13,14 +13,14 @@ where
     }
 }
 
-#[::yew_functional::function_component(Comp1)]
+#[::yew::function_component(Comp1)]
 fn comp1<T1, T2>(_props: &()) -> ::yew::Html {
     ::yew::html! {
         <p></p>
     }
 }
 
-#[::yew_functional::function_component(ConstGenerics)]
+#[::yew::function_component(ConstGenerics)]
 fn const_generics<const N: i32>() -> ::yew::Html {
     ::yew::html! {
         <div>
@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+#[derive(Clone, ::yew::Properties, PartialEq)]
+pub struct ListProps<T: Clone + PartialEq + 'static> {
+    pub items: Vec<T>,
+}
+
+#[::yew::function_component(List)]
+fn list<T>(props: &ListProps<T>) -> ::yew::Html
+where
+    T: Clone + PartialEq + Display + 'static,
+{
+    ::yew::html! {
+        <ul>
+            { for props.items.iter().map(|item| ::yew::html! { <li>{ item }</li> }) }
+        </ul>
+    }
+}
+
+#[::yew::function_component(Table)]
+pub fn table<R: Clone + PartialEq + Display + 'static, const COLUMNS: usize>(
+    props: &ListProps<R>,
+) -> ::yew::Html {
+    let _state = ::yew::functional::use_state(|| 0);
+    ::yew::html! {
+        <table>
+            { for props.items.iter().map(|row| ::yew::html! {
+                <tr>{ for (0..COLUMNS).map(|_| ::yew::html! { <td>{ row }</td> }) }</tr>
+            }) }
+        </table>
+    }
+}
+
+fn compile_pass() {
+    ::yew::html! { <List<usize> items={vec![1, 2]} /> };
+    ::yew::html! { <List<String> items={vec!["a".to_string()]} /> };
+    ::yew::html! { <Table<u8, 3> items={vec![1]} /> };
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[::yew::function_component(TypeName)]
+fn type_name<T: 'static>() -> ::yew::Html {
+    ::yew::html! {
+        <p>{ ::std::any::type_name::<T>() }</p>
+    }
+}
+
+fn compile_pass() {
+    ::yew::html! { <TypeName<usize> /> };
+    ::yew::html! { <TypeName<String> /> };
+}
+
+fn main() {}
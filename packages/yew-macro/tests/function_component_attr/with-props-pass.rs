@@ -3,7 +3,7 @@ struct Props {
     a: usize,
 }
 
-#[::yew_functional::function_component(Comp)]
+#[::yew::function_component(Comp)]
 fn comp(props: &Props) -> ::yew::Html {
     ::yew::html! {
         <p>
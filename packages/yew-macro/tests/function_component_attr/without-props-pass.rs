@@ -1,4 +1,4 @@
-#[::yew_functional::function_component(Comp)]
+#[::yew::function_component(Comp)]
 fn comp() -> ::yew::Html {
     ::yew::html! {
         <p>
@@ -0,0 +1,16 @@
+use yew::prelude::*;
+
+#[hook]
+fn counter() -> UseStateHandle<i32> {
+    use_state(|| 0)
+}
+
+#[hook(name)]
+fn use_counter() -> UseStateHandle<i32> {
+    use_state(|| 0)
+}
+
+#[hook]
+struct Counter;
+
+fn main() {}
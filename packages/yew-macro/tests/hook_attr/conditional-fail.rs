@@ -0,0 +1,43 @@
+use yew::prelude::*;
+
+#[hook]
+fn use_conditional(enabled: bool) -> Option<UseStateHandle<i32>> {
+    if enabled {
+        Some(use_state(|| 0))
+    } else {
+        None
+    }
+}
+
+#[hook]
+fn use_in_match(value: Option<i32>) {
+    match value {
+        Some(_) => {
+            yew::functional::use_ref(|| 0);
+        }
+        None => {}
+    }
+}
+
+#[hook]
+fn use_in_loop() {
+    for _ in 0..2 {
+        use_state(|| 0);
+    }
+    while use_state(|| true).clone() == use_state(|| false) {}
+}
+
+#[hook]
+fn use_in_closure() {
+    let _ = || use_state(|| 0);
+    let _ = true && *use_state(|| true);
+}
+
+#[function_component(Comp)]
+fn comp() -> Html {
+    let items = vec![1, 2];
+    let _states: Vec<_> = items.iter().map(|_| use_state(|| 0)).collect();
+    html! {}
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+use yew::prelude::*;
+
+#[hook]
+fn use_after_return(enabled: bool) -> Option<UseStateHandle<i32>> {
+    if !enabled {
+        return None;
+    }
+    Some(use_state(|| 0))
+}
+
+#[hook]
+fn use_after_try(value: Option<i32>) -> Option<UseStateHandle<i32>> {
+    let value = value?;
+    Some(use_state(move || value))
+}
+
+#[hook]
+fn use_before_return() -> UseStateHandle<i32> {
+    let state = use_state(|| 0);
+    let _ = || -> Option<i32> { Some(Some(1)?) };
+    let _ = yew::functional::use_ref(|| 0);
+    return state;
+}
+
+#[function_component(Comp)]
+fn comp() -> Html {
+    if use_state(|| false).clone() == use_state(|| true) {
+        return html! {};
+    }
+    let _ = use_state(|| 0);
+    html! {}
+}
+
+#[function_component(Suspendable)]
+fn suspendable() -> HtmlResult {
+    if *use_state(|| false) {
+        return Ok(html! {});
+    }
+    let _ = use_state(|| 0);
+    Ok(html! {})
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+use yew::prelude::*;
+
+#[hook]
+fn use_counter(initial: i32) -> UseStateHandle<i32> {
+    let counter = use_state(move || initial);
+    {
+        let counter = counter.clone();
+        use_effect(move || {
+            // Not a hook call, only the name of a variable
+            let use_counter = *counter;
+            if use_counter > 10 {
+                counter.set(0);
+            }
+            || ()
+        });
+    }
+    counter
+}
+
+#[hook]
+fn use_doubled_counter() -> i32 {
+    let counter = use_counter(0);
+    *counter * 2
+}
+
+#[function_component(Counter)]
+fn counter() -> Html {
+    let counter = use_doubled_counter();
+    let on_click = use_callback(|_: MouseEvent, _| (), ());
+
+    html! {
+        <button onclick={on_click}>{ counter }</button>
+    }
+}
+
+#[function_component(Greeting)]
+fn greeting() -> HtmlResult {
+    // Suspends the component, so the hooks after it are still called in the same order
+    let name = yew::suspense::use_future(|| async { "Yew" })?;
+    let greeting = use_state(|| "Hello");
+
+    Ok(html! { <p>{ format!("{}, {}!", *greeting, *name) }</p> })
+}
+
+fn main() {
+    let _ = html! { <Counter /> };
+    let _ = html! { <Greeting /> };
+}
@@ -116,6 +116,7 @@ fn compile_fail() {
     html! { <Child {std::f64::consts::PI} /> };
     html! { <Child {7 + 6} /> };
     html! { <Child {children.len()} /> };
+    html! { <Child on:my-event={Callback::from(|_: Event| ())} /> };
 
 }
 
@@ -3,6 +3,7 @@ use yew::prelude::*;
 
 fn compile_pass() {
     let onclick = Callback::from(|_: MouseEvent| ());
+    let onfocus: Option<Callback<FocusEvent>> = None;
     let parent_ref = NodeRef::default();
 
     let dyn_tag = || String::from("test");
@@ -43,6 +44,7 @@ fn compile_pass() {
             <img class={classes!("avatar", "hidden")} src="http://pic.com" />
             <img class="avatar hidden" />
             <button onclick={&onclick} {onclick} />
+            <button onfocus={&onfocus} {onfocus} />
             <a href="http://google.com" />
             <custom-tag-a>
                 <custom-tag-b />
@@ -65,6 +67,8 @@ fn compile_pass() {
             <track kind={Some(Cow::Borrowed("subtitles"))} src={cow_none.clone()} />
             <track kind={Some(Cow::Borrowed("5"))} mixed="works" />
             <input value={Some(Cow::Borrowed("value"))} onblur={Some(Callback::from(|_| ()))} />
+            <color-picker on:color-picked={Callback::from(|_: yew::events::CustomEvent| ())} />
+            <div on:my-event={Some(Callback::from(|_: Event| ()))} on:scroll={yew::html::WithOptions::passive(Callback::from(|_: Event| ()))} />
         </div>
     };
 
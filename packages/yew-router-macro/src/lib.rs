@@ -9,21 +9,59 @@ use syn::parse_macro_input;
 ///  `yew_router::Routable` trait and `const`s for the routes passed which are used with `Route`
 /// component.
 ///
+/// The path may contain parameters, which are stored in the fields of the variant:
+/// - `:name` matches a single segment.
+/// - `:name?` matches a single segment which may be missing. Its field must be an `Option`.
+/// - `*name` matches the remainder of the path.
+/// - `*` matches the remainder of the path, which is recognized by the nested `Routable` stored
+///   in an additional last unnamed field of the variant. If there's no such field, the remainder
+///   is left to a nested `Router`.
+///
+/// Named fields are matched with the parameter of the same name, and unnamed fields with the
+/// parameters in order. Every parameter must have a field, and every field a parameter.
+///
+/// The only exception is the field marked with the `query` attribute, which stores the query
+/// string of the URL. Its type must implement `serde::Serialize` and `serde::Deserialize`, and
+/// the route doesn't match if the query string can't be deserialized into it.
+///
 /// # Example
 ///
 /// ```
+/// # use serde::{Deserialize, Serialize};
 /// # use yew_router::Routable;
-/// #[derive(Debug, Clone, Copy, PartialEq, Routable)]
+/// #[derive(Debug, Clone, PartialEq, Routable)]
 /// enum Routes {
 ///     #[at("/")]
 ///     Home,
 ///     #[at("/secure")]
 ///     Secure,
+///     #[at("/posts/:id")]
+///     Post { id: u32 },
+///     #[at("/users/:id/:tab?")]
+///     User(u32, Option<String>),
+///     #[at("/search")]
+///     Search(#[query] SearchQuery),
+///     #[at("/admin/*")]
+///     Admin(AdminRoutes),
 ///     #[at("/404")]
 ///     NotFound,
 /// }
+///
+/// #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// struct SearchQuery {
+///     q: String,
+///     page: Option<u32>,
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Routable)]
+/// enum AdminRoutes {
+///     #[at("/")]
+///     Dashboard,
+///     #[at("/settings")]
+///     Settings,
+/// }
 /// ```
-#[proc_macro_derive(Routable, attributes(at, not_found))]
+#[proc_macro_derive(Routable, attributes(at, not_found, query))]
 pub fn routable_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as Routable);
     routable_derive_impl(input).into()
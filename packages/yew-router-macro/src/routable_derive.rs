@@ -1,5 +1,5 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
@@ -7,10 +7,158 @@ use syn::{Data, DeriveInput, Fields, Ident, LitStr, Variant};
 
 const AT_ATTR_IDENT: &str = "at";
 const NOT_FOUND_ATTR_IDENT: &str = "not_found";
+const QUERY_ATTR_IDENT: &str = "query";
+/// Name of the parameter capturing the remainder of the path for a nested route.
+const NESTED_PARAM: &str = "__rest";
+
+/// A segment of the path of an `at` attribute.
+enum Segment {
+    /// `name`
+    Static(String),
+    /// `:name`, or `:name?` if the segment is optional
+    Param { name: String, optional: bool },
+    /// `*name`, which captures the remainder of the path
+    Wildcard(String),
+    /// `*`, which captures the remainder of the path for the nested route, or for a nested
+    /// router if the variant has no field for it
+    Nested,
+}
+
+impl Segment {
+    fn param_name(&self) -> Option<&str> {
+        match self {
+            Segment::Param { name, .. } | Segment::Wildcard(name) => Some(name),
+            Segment::Static(_) | Segment::Nested => None,
+        }
+    }
+}
+
+/// The parsed path of an `at` attribute.
+struct RoutePath {
+    segments: Vec<Segment>,
+    /// Whether the variant stores the nested route matching the remainder of the path
+    nested_field: bool,
+    /// The index of the field marked with the `query` attribute, which stores the query string
+    query_field: Option<usize>,
+}
+
+impl RoutePath {
+    fn parse(lit: &LitStr) -> syn::Result<Self> {
+        let value = lit.value();
+        // route-recognizer accepts paths without a leading `/`
+        let raw_segments = value.trim_start_matches('/').split('/').collect::<Vec<_>>();
+        let mut segments = Vec::with_capacity(raw_segments.len());
+
+        for (i, segment) in raw_segments.iter().enumerate() {
+            let is_last = i + 1 == raw_segments.len();
+            let segment = if let Some(name) = segment.strip_prefix(':') {
+                let (name, optional) = match name.strip_suffix('?') {
+                    Some(name) => (name, true),
+                    None => (name, false),
+                };
+                if name.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "parameters must have a name, like `:id`",
+                    ));
+                }
+                Segment::Param {
+                    name: name.to_string(),
+                    optional,
+                }
+            } else if let Some(name) = segment.strip_prefix('*') {
+                if !is_last {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "a wildcard must be the last segment of the path",
+                    ));
+                }
+                if name.is_empty() {
+                    Segment::Nested
+                } else {
+                    Segment::Wildcard(name.to_string())
+                }
+            } else {
+                Segment::Static(segment.to_string())
+            };
+            segments.push(segment);
+        }
+
+        let mut names = Vec::new();
+        for name in segments.iter().filter_map(Segment::param_name) {
+            if names.contains(&name) {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    format!("parameter `{}` is used more than once", name),
+                ));
+            }
+            names.push(name);
+        }
+
+        Ok(Self {
+            segments,
+            nested_field: false,
+            query_field: None,
+        })
+    }
+
+    fn params(&self) -> impl Iterator<Item = &Segment> {
+        self.segments
+            .iter()
+            .filter(|segment| segment.param_name().is_some())
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self.segments.last(), Some(Segment::Nested))
+    }
+
+    /// Returns the paths passed to the route recognizer, one for every combination of optional
+    /// segments.
+    fn patterns(&self) -> Vec<String> {
+        let mut patterns = vec![String::new()];
+        for segment in &self.segments {
+            match segment {
+                Segment::Static(name) => push_segment(&mut patterns, name),
+                Segment::Param { name, optional } => {
+                    let with_param = patterns.clone();
+                    if !optional {
+                        patterns.clear();
+                    }
+                    for mut pattern in with_param {
+                        pattern.push_str("/:");
+                        pattern.push_str(name);
+                        patterns.push(pattern);
+                    }
+                }
+                Segment::Wildcard(name) => push_segment(&mut patterns, &format!("*{}", name)),
+                Segment::Nested => {
+                    // the nested route is the root route if the remainder is empty
+                    let without_rest = patterns.clone();
+                    push_segment(&mut patterns, &format!("*{}", NESTED_PARAM));
+                    patterns.extend(without_rest);
+                }
+            }
+        }
+
+        for pattern in patterns.iter_mut() {
+            if pattern.is_empty() {
+                pattern.push('/');
+            }
+        }
+        patterns
+    }
+}
+
+fn push_segment(patterns: &mut [String], segment: &str) {
+    for pattern in patterns.iter_mut() {
+        pattern.push('/');
+        pattern.push_str(segment);
+    }
+}
 
 pub struct Routable {
     ident: Ident,
-    ats: Vec<LitStr>,
+    paths: Vec<RoutePath>,
     variants: Punctuated<Variant, syn::token::Comma>,
     not_found_route: Option<Ident>,
 }
@@ -35,12 +183,12 @@ impl Parse for Routable {
             }
         };
 
-        let (not_found_route, ats) = parse_variants_attributes(&data.variants)?;
+        let (not_found_route, paths) = parse_variants_attributes(&data.variants)?;
 
         Ok(Self {
             ident,
             variants: data.variants,
-            ats,
+            paths,
             not_found_route,
         })
     }
@@ -48,20 +196,13 @@ impl Parse for Routable {
 
 fn parse_variants_attributes(
     variants: &Punctuated<Variant, syn::token::Comma>,
-) -> syn::Result<(Option<Ident>, Vec<LitStr>)> {
+) -> syn::Result<(Option<Ident>, Vec<RoutePath>)> {
     let mut not_founds = vec![];
-    let mut ats: Vec<LitStr> = vec![];
+    let mut paths = vec![];
 
     let mut not_found_attrs = vec![];
 
     for variant in variants.iter() {
-        if let Fields::Unnamed(ref field) = variant.fields {
-            return Err(syn::Error::new(
-                field.span(),
-                "only named fields are supported",
-            ));
-        }
-
         let attrs = &variant.attrs;
         let at_attrs = attrs
             .iter()
@@ -88,7 +229,10 @@ fn parse_variants_attributes(
         };
 
         let lit = attr.parse_args::<LitStr>()?;
-        ats.push(lit);
+        let mut path = RoutePath::parse(&lit)?;
+        path.query_field = query_field(variant)?;
+        path.nested_field = check_fields(variant, &lit, &path)?;
+        paths.push(path);
 
         for attr in attrs.iter() {
             if attr.path.is_ident(NOT_FOUND_ATTR_IDENT) {
@@ -105,33 +249,271 @@ fn parse_variants_attributes(
         ));
     }
 
-    Ok((not_founds.into_iter().next(), ats))
+    Ok((not_founds.into_iter().next(), paths))
+}
+
+/// Returns the index of the field of `variant` marked with the `query` attribute.
+fn query_field(variant: &Variant) -> syn::Result<Option<usize>> {
+    let query_fields = variant
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| {
+            field
+                .attrs
+                .iter()
+                .any(|attr| attr.path.is_ident(QUERY_ATTR_IDENT))
+        })
+        .collect::<Vec<_>>();
+
+    match query_fields.as_slice() {
+        [] => Ok(None),
+        [(index, _)] => Ok(Some(*index)),
+        [_, rest @ ..] => {
+            let fields = rest.iter().map(|(_, field)| field);
+            Err(syn::Error::new_spanned(
+                quote! { #(#fields)* },
+                format!("only one field can have the {} attribute", QUERY_ATTR_IDENT),
+            ))
+        }
+    }
+}
+
+/// Checks that the fields of `variant` match the parameters of its path, and returns whether
+/// the variant has a field for the nested route.
+///
+/// Named fields must have the name of a parameter, while unnamed fields are matched with the
+/// parameters in order. A nested route is stored in an additional last unnamed field. If there's
+/// no such field, the remainder of the path is left to a nested router. The field storing the
+/// query string is skipped.
+fn check_fields(variant: &Variant, lit: &LitStr, path: &RoutePath) -> syn::Result<bool> {
+    let ident = &variant.ident;
+    match &variant.fields {
+        Fields::Unit => match path.params().next() {
+            Some(param) => Err(syn::Error::new_spanned(
+                lit,
+                format!(
+                    "variant `{}` has no field for the parameter `{}`",
+                    ident,
+                    param.param_name().unwrap()
+                ),
+            )),
+            None => Ok(false),
+        },
+        Fields::Named(fields) => {
+            let mut errors = vec![];
+            for param in path.params().filter_map(Segment::param_name) {
+                let has_field = fields
+                    .named
+                    .iter()
+                    .any(|field| field.ident.as_ref().unwrap() == param);
+                if !has_field {
+                    errors.push(syn::Error::new_spanned(
+                        lit,
+                        format!("variant `{}` has no field named `{}`", ident, param),
+                    ));
+                }
+            }
+            for (index, field) in fields.named.iter().enumerate() {
+                if path.query_field == Some(index) {
+                    continue;
+                }
+                let field_ident = field.ident.as_ref().unwrap();
+                let has_param = path
+                    .params()
+                    .filter_map(Segment::param_name)
+                    .any(|param| field_ident == param);
+                if !has_param {
+                    errors.push(syn::Error::new_spanned(
+                        field_ident,
+                        format!(
+                            "field `{}` is not a parameter of the path `{}`",
+                            field_ident,
+                            lit.value()
+                        ),
+                    ));
+                }
+            }
+
+            let mut combined: Option<syn::Error> = None;
+            for error in errors {
+                match &mut combined {
+                    Some(combined) => combined.combine(error),
+                    None => combined = Some(error),
+                }
+            }
+            match combined {
+                Some(errors) => Err(errors),
+                None => Ok(false),
+            }
+        }
+        Fields::Unnamed(fields) => {
+            let params = path.params().count();
+            let len = fields.unnamed.len() - path.query_field.map_or(0, |_| 1);
+            if len == params {
+                Ok(false)
+            } else if len == params + 1 && path.is_nested() {
+                Ok(true)
+            } else {
+                let expected = if path.is_nested() {
+                    format!("{} or {}", params, params + 1)
+                } else {
+                    params.to_string()
+                };
+                let besides_query = match path.query_field {
+                    Some(_) => " besides the query field",
+                    None => "",
+                };
+                Err(syn::Error::new_spanned(
+                    fields,
+                    format!(
+                        "expected {} unnamed fields{} for the path `{}`, found {}",
+                        expected,
+                        besides_query,
+                        lit.value(),
+                        len
+                    ),
+                ))
+            }
+        }
+    }
+}
+
+/// Returns the expression parsing the parameter `segment` from `params`.
+fn parse_param(segment: &Segment) -> TokenStream {
+    let name = segment.param_name().unwrap();
+    match segment {
+        Segment::Param { optional: true, .. } => quote! {
+            match params.get(#name) {
+                ::std::option::Option::Some(param) => ::std::option::Option::Some(param.parse().ok()?),
+                ::std::option::Option::None => ::std::option::Option::None,
+            }
+        },
+        _ => quote! { params.get(#name)?.parse().ok()? },
+    }
+}
+
+/// Returns the expression deserializing the field storing the query string from `query`.
+fn deserialize_query() -> TokenStream {
+    quote! { ::yew_router::__macro::deserialize_query(query)? }
+}
+
+/// Returns the statements appending `segment` to the `__path` string.
+///
+/// `value` is the binding of the field of the parameter, and is ignored for static segments. If
+/// `fallible` is set, the statements are in `try_to_path` and return the errors of nested routes.
+fn push_segment_value(segment: &Segment, value: Option<&Ident>, fallible: bool) -> TokenStream {
+    match segment {
+        Segment::Static(name) => {
+            let segment = format!("/{}", name);
+            quote! { __path.push_str(#segment); }
+        }
+        Segment::Param { optional: true, .. } => quote! {
+            if let ::std::option::Option::Some(#value) = #value {
+                __path.push('/');
+                __path.push_str(&::std::string::ToString::to_string(#value));
+            }
+        },
+        Segment::Param { .. } | Segment::Wildcard(_) => quote! {
+            __path.push('/');
+            __path.push_str(&::std::string::ToString::to_string(#value));
+        },
+        Segment::Nested => match value {
+            Some(value) => {
+                let rest = if fallible {
+                    quote! { ::yew_router::Routable::try_to_path(#value)? }
+                } else {
+                    quote! { ::yew_router::Routable::to_path(#value) }
+                };
+                quote! {
+                    let __rest = #rest;
+                    // the root of the nested route is the path of the variant itself
+                    match __rest.strip_prefix('/') {
+                        ::std::option::Option::Some(rest) if rest.is_empty() || rest.starts_with('?') => {
+                            __path.push_str(rest)
+                        }
+                        _ => __path.push_str(&__rest),
+                    }
+                }
+            }
+            // the remainder belongs to a nested router
+            None => quote! {},
+        },
+    }
 }
 
 impl Routable {
     fn build_from_path(&self) -> TokenStream {
-        let from_path_matches = self.variants.iter().enumerate().map(|(i, variant)| {
-            let ident = &variant.ident;
-            let right = match &variant.fields {
-                Fields::Unit => quote! { Self::#ident },
-                Fields::Named(field) => {
-                    let fields = field.named.iter().map(|it| {
-                        //named fields have idents
-                        it.ident.as_ref().unwrap()
-                    });
-                    quote! { Self::#ident { #(#fields: params.get(stringify!(#fields))?.parse().ok()?)*, } }
+        let from_path_matches = self
+            .variants
+            .iter()
+            .zip(&self.paths)
+            .map(|(variant, path)| {
+                let ident = &variant.ident;
+                let right = match &variant.fields {
+                    Fields::Unit => quote! { Self::#ident },
+                    Fields::Named(named) => {
+                        let mut fields = path
+                            .params()
+                            .map(|param| {
+                                let field = format_ident!("{}", param.param_name().unwrap());
+                                let value = parse_param(param);
+                                quote! { #field: #value }
+                            })
+                            .collect::<Vec<_>>();
+                        if let Some(index) = path.query_field {
+                            let field = &named.named[index].ident;
+                            let value = deserialize_query();
+                            fields.push(quote! { #field: #value });
+                        }
+                        quote! { Self::#ident { #(#fields),* } }
+                    }
+                    Fields::Unnamed(fields) => {
+                        let mut values = path.params().map(parse_param).collect::<Vec<_>>();
+                        if path.nested_field {
+                            let ty = &fields
+                                .unnamed
+                                .iter()
+                                .enumerate()
+                                .filter(|(index, _)| path.query_field != Some(*index))
+                                .last()
+                                .unwrap()
+                                .1
+                                .ty;
+                            values.push(quote! {
+                                <#ty as ::yew_router::Routable>::recognize(&::std::format!(
+                                    "/{}?{}",
+                                    params.get(#NESTED_PARAM).map_or("", |rest| *rest),
+                                    query
+                                ))?
+                            });
+                        }
+                        if let Some(index) = path.query_field {
+                            values.insert(index, deserialize_query());
+                        }
+                        quote! { Self::#ident(#(#values),*) }
+                    }
+                };
+
+                let left = path.patterns();
+                quote! {
+                    #(#left)|* => ::std::option::Option::Some(#right)
                 }
-                Fields::Unnamed(_) => unreachable!(), // already checked
-            };
+            });
 
-            let left = self.ats.get(i).unwrap();
-            quote! {
-                #left => ::std::option::Option::Some(#right)
+        quote! {
+            fn from_path(
+                path: &str,
+                params: &::std::collections::HashMap<&str, &str>,
+            ) -> ::std::option::Option<Self> {
+                Self::from_path_with_query(path, params, "")
             }
-        });
 
-        quote! {
-            fn from_path(path: &str, params: &::std::collections::HashMap<&str, &str>) -> ::std::option::Option<Self> {
+            fn from_path_with_query(
+                path: &str,
+                params: &::std::collections::HashMap<&str, &str>,
+                query: &str,
+            ) -> ::std::option::Option<Self> {
                 match path {
                     #(#from_path_matches),*,
                     _ => ::std::option::Option::None,
@@ -140,39 +522,111 @@ impl Routable {
         }
     }
 
-    fn build_to_path(&self) -> TokenStream {
-        let to_path_matches = self.variants.iter().enumerate().map(|(i, variant)| {
-            let ident = &variant.ident;
-            let mut right = self.ats.get(i).unwrap().value();
-
-            match &variant.fields {
-                Fields::Unit => quote! { Self::#ident => ::std::string::ToString::to_string(#right) },
-                Fields::Named(field) => {
-                    let fields = field
-                        .named
-                        .iter()
-                        .map(|it| it.ident.as_ref().unwrap())
-                        .collect::<Vec<_>>();
-
-                    for field in fields.iter() {
-                        // :param -> {param}
-                        // so we can pass it to `format!("...", param)`
-                        right = right.replace(&format!(":{}", field), &format!("{{{}}}", field))
+    /// Builds the `match` converting the route to a path. If `fallible` is set, it evaluates to a
+    /// `Result` which is an error if a query can't be serialized, and otherwise such queries are
+    /// omitted.
+    fn build_to_path_match(&self, fallible: bool) -> TokenStream {
+        let to_path_matches = self
+            .variants
+            .iter()
+            .zip(&self.paths)
+            .map(|(variant, path)| {
+                let ident = &variant.ident;
+
+                let (pattern, mut values) = match &variant.fields {
+                    Fields::Unit => (quote! { Self::#ident }, vec![]),
+                    Fields::Named(field) => {
+                        let fields = field
+                            .named
+                            .iter()
+                            .map(|it| it.ident.clone().unwrap())
+                            .collect::<Vec<_>>();
+                        let mut values = path
+                            .params()
+                            .map(|param| format_ident!("{}", param.param_name().unwrap()))
+                            .collect::<Vec<_>>();
+                        if let Some(index) = path.query_field {
+                            values.push(fields[index].clone());
+                        }
+                        (quote! { Self::#ident { #(#fields),* } }, values)
                     }
-
+                    Fields::Unnamed(field) => {
+                        let mut fields = (0..field.unnamed.len())
+                            .map(|i| format_ident!("__field{}", i))
+                            .collect::<Vec<_>>();
+                        let pattern = quote! { Self::#ident(#(#fields),*) };
+                        // the query field is moved to the end, after the parameters
+                        if let Some(index) = path.query_field {
+                            let query = fields.remove(index);
+                            fields.push(query);
+                        }
+                        (pattern, fields)
+                    }
+                };
+                let push_query = path.query_field.map(|_| {
+                    let value = values.pop();
+                    let query = if fallible {
+                        quote! { ::yew_router::__macro::serialize_query(#value)? }
+                    } else {
+                        quote! { ::yew_router::__macro::serialize_query_or_log(#value) }
+                    };
                     quote! {
-                        Self::#ident { #(#fields),* } => ::std::format!(#right, #(#fields = #fields),*)
+                        let __query = #query;
+                        if !__query.is_empty() {
+                            // the nested route may have a query as well
+                            __path.push(if __path.contains('?') { '&' } else { '?' });
+                            __path.push_str(&__query);
+                        }
+                    }
+                });
+
+                let mut values = values.iter();
+                let pushes = path.segments.iter().map(|segment| {
+                    let value = match segment {
+                        Segment::Static(_) => None,
+                        _ => values.next(),
+                    };
+                    push_segment_value(segment, value, fallible)
+                });
+                let path = if fallible {
+                    quote! { ::std::result::Result::Ok(__path) }
+                } else {
+                    quote! { __path }
+                };
+
+                quote! {
+                    #pattern => {
+                        let mut __path = ::std::string::String::new();
+                        #(#pushes)*
+                        if __path.is_empty() || __path.starts_with('?') {
+                            __path.insert(0, '/');
+                        }
+                        #push_query
+                        #path
                     }
                 }
-                Fields::Unnamed(_) => unreachable!(), // already checked
+            });
+
+        quote! {
+            match self {
+                #(#to_path_matches),*,
             }
-        });
+        }
+    }
+
+    fn build_to_path(&self) -> TokenStream {
+        let to_path = self.build_to_path_match(false);
+        let try_to_path = self.build_to_path_match(true);
 
         quote! {
             fn to_path(&self) -> ::std::string::String {
-                match self {
-                    #(#to_path_matches),*,
-                }
+                #to_path
+            }
+
+            fn try_to_path(
+                &self,
+            ) -> ::std::result::Result<::std::string::String, ::yew_router::__macro::QueryError> {
+                #try_to_path
             }
         }
     }
@@ -180,7 +634,7 @@ impl Routable {
 
 pub fn routable_derive_impl(input: Routable) -> TokenStream {
     let Routable {
-        ats,
+        paths,
         not_found_route,
         ident,
         ..
@@ -189,6 +643,8 @@ pub fn routable_derive_impl(input: Routable) -> TokenStream {
     let from_path = input.build_from_path();
     let to_path = input.build_to_path();
 
+    let routes = paths.iter().flat_map(RoutePath::patterns);
+
     let not_found_route = match not_found_route {
         Some(route) => quote! { ::std::option::Option::Some(Self::#route) },
         None => quote! { ::std::option::Option::None },
@@ -212,7 +668,7 @@ pub fn routable_derive_impl(input: Routable) -> TokenStream {
             #to_path
 
             fn routes() -> ::std::vec::Vec<&'static str> {
-                ::std::vec![#(#ats),*]
+                ::std::vec![#(#routes),*]
             }
 
             fn not_found_route() -> ::std::option::Option<Self> {
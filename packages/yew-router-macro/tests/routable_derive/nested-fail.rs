@@ -0,0 +1,19 @@
+#[derive(Clone, yew_router::Routable)]
+enum AdminRoutes {
+    #[at("/")]
+    Home,
+}
+
+#[derive(yew_router::Routable)]
+enum Routes {
+    #[at("/admin/*")]
+    Admin { route: AdminRoutes },
+}
+
+#[derive(yew_router::Routable)]
+enum RoutesTwo {
+    #[at("/admin/*")]
+    Admin(AdminRoutes, u32),
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#[derive(yew_router::Routable)]
+enum Routes {
+    #[at("/posts/:id")]
+    Post { slug: String },
+}
+
+#[derive(yew_router::Routable)]
+enum RoutesTwo {
+    #[at("/users/:id")]
+    User,
+}
+
+#[derive(yew_router::Routable)]
+enum RoutesThree {
+    #[at("/pages/:id/:id")]
+    Page { id: u32 },
+}
+
+#[derive(yew_router::Routable)]
+enum RoutesFour {
+    #[at("/posts/:id/:page")]
+    Post(u32),
+}
+
+#[derive(yew_router::Routable)]
+enum RoutesFive {
+    #[at("/files/*path/raw")]
+    Files { path: String },
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+struct Query {
+    page: u32,
+}
+
+#[derive(Debug, PartialEq, Clone, yew_router::Routable)]
+enum Routes {
+    #[at("/search")]
+    Search(#[query] Query, #[query] Query),
+}
+
+#[derive(Debug, PartialEq, Clone, yew_router::Routable)]
+enum MoreRoutes {
+    #[at("/posts/:id")]
+    Post(#[query] Query),
+}
+
+#[derive(Debug, PartialEq, Clone, yew_router::Routable)]
+enum OtherRoutes {
+    #[at("/posts/:id")]
+    Post {
+        id: u32,
+        #[query]
+        query: Query,
+        page: u32,
+    },
+}
+
+fn main() {}
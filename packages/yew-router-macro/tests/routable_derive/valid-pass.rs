@@ -6,9 +6,48 @@ enum Routes {
     One,
     #[at("/two/:id")]
     Two { id: u32 },
+    #[at("/three/:id/:name")]
+    Three(u32, ::std::string::String),
+    #[at("/four/:page?")]
+    Four { page: ::std::option::Option<u32> },
+    #[at("/files/*path")]
+    Files { path: ::std::string::String },
+    #[at("/admin/*")]
+    Admin(AdminRoutes),
+    #[at("/orgs/:id/*")]
+    Org(u32, AdminRoutes),
+    #[at("/settings/*")]
+    Settings,
+    #[at("/users/:id/*")]
+    User { id: u32 },
+    #[at("/search")]
+    Search(#[query] Query),
+    #[at("/posts/:id")]
+    Post {
+        id: u32,
+        #[query]
+        query: Query,
+    },
+    #[at("tags/:tag")]
+    Tag { tag: ::std::string::String },
     #[at("/404")]
     #[not_found]
     NotFound,
 }
 
+#[derive(Debug, PartialEq, Clone, ::yew_router::Routable)]
+enum AdminRoutes {
+    #[at("/")]
+    Home,
+    #[at("/users/:id")]
+    User(u32),
+    #[at("/logs/:page")]
+    Logs(#[query] Query, u32),
+}
+
+#[derive(Debug, PartialEq, Clone, ::serde::Serialize, ::serde::Deserialize)]
+struct Query {
+    q: ::std::option::Option<::std::string::String>,
+}
+
 fn main() {}
@@ -1,30 +1,53 @@
-use crate::{service, Routable};
+use crate::__macro::{serialize_query, QueryError};
+use crate::history::history;
+use crate::router::RouterContext;
+use crate::{navigation, Routable};
+use serde::Serialize;
 use yew::prelude::*;
 
 /// Props for [`Link`]
 #[derive(Properties, Clone, PartialEq)]
-pub struct LinkProps<R: Routable + Clone> {
+pub struct LinkProps<R: Routable + Clone, Q: Clone + PartialEq = ()> {
     /// CSS classes to add to the anchor element (optional).
     #[prop_or_default]
     pub classes: Classes,
     /// Route that will be pushed when the anchor is clicked.
     pub route: R,
+    /// Query parameters appended to the path of the route (optional).
+    #[prop_or_default]
+    pub query: Option<Q>,
     pub children: Children,
 }
 
 /// A wrapper around `<a>` tag to be used with [`Router`](crate::Router)
-pub struct Link<R: Routable + Clone + PartialEq + 'static> {
+///
+/// If the link is rendered by a router for the same route type, its route is relative to the
+/// base of that router, so links to the routes of nested routers point to the right path.
+///
+/// The `query` prop is serialized with `serde_urlencoded`, so its type is the second type
+/// parameter of the link: `<Link<Route, SearchQuery> route={Route::Search} query={Some(query)}>`.
+/// If it or the query of the route can't be serialized, the anchor has no `href` and clicking it
+/// logs the error instead of navigating.
+pub struct Link<R, Q = ()>
+where
+    R: Routable + Clone + PartialEq + 'static,
+    Q: Serialize + Clone + PartialEq + 'static,
+{
     link: ComponentLink<Self>,
-    props: LinkProps<R>,
+    props: LinkProps<R, Q>,
 }
 
 pub enum Msg {
     OnClick,
 }
 
-impl<R: Routable + Clone + PartialEq + 'static> Component for Link<R> {
+impl<R, Q> Component for Link<R, Q>
+where
+    R: Routable + Clone + PartialEq + 'static,
+    Q: Serialize + Clone + PartialEq + 'static,
+{
     type Message = Msg;
-    type Properties = LinkProps<R>;
+    type Properties = LinkProps<R, Q>;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
         Self { link, props }
@@ -33,7 +56,13 @@ impl<R: Routable + Clone + PartialEq + 'static> Component for Link<R> {
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::OnClick => {
-                service::push_route(self.props.route.clone());
+                match self.path() {
+                    Ok(path) => navigation::push(&path),
+                    Err(error) => weblog::console_error!(format!(
+                        "the query of the link can't be serialized: {}",
+                        error
+                    )),
+                }
                 false
             }
         }
@@ -47,7 +76,7 @@ impl<R: Routable + Clone + PartialEq + 'static> Component for Link<R> {
     fn view(&self) -> Html {
         html! {
             <a class={self.props.classes.clone()}
-                href={self.props.route.to_path()}
+                href={self.path().ok().map(|path| history().href(&path))}
                 onclick={self.link.callback(|e: MouseEvent| {
                     e.prevent_default();
                     Msg::OnClick
@@ -58,3 +87,24 @@ impl<R: Routable + Clone + PartialEq + 'static> Component for Link<R> {
         }
     }
 }
+
+impl<R, Q> Link<R, Q>
+where
+    R: Routable + Clone + PartialEq + 'static,
+    Q: Serialize + Clone + PartialEq + 'static,
+{
+    fn path(&self) -> Result<String, QueryError> {
+        let mut path = match self.link.consume_context::<RouterContext<R>>() {
+            Some(context) => context.try_path(&self.props.route)?,
+            None => self.props.route.try_to_path()?,
+        };
+
+        let query = self.props.query.as_ref().map(serialize_query).transpose()?;
+        if let Some(query) = query.filter(|query| !query.is_empty()) {
+            // the route may have a query as well
+            path.push(if path.contains('?') { '&' } else { '?' });
+            path.push_str(&query);
+        }
+        Ok(path)
+    }
+}
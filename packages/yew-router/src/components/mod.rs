@@ -1,4 +1,6 @@
 //! Components to interface with [Router][crate::Router].
 
 mod link;
+mod redirect;
 pub use link::*;
+pub use redirect::*;
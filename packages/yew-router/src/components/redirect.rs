@@ -0,0 +1,50 @@
+use crate::router::RouterContext;
+use crate::{navigation, Routable};
+use yew::prelude::*;
+
+/// Props for [`Redirect`]
+#[derive(Properties, Clone, PartialEq)]
+pub struct RedirectProps<R: Routable + Clone> {
+    /// Route that will replace the current history entry.
+    pub to: R,
+}
+
+/// Navigates to a route when rendered, replacing the current history entry.
+///
+/// Like [`Link`](super::Link), the route is relative to the base of a
+/// [`Router`](crate::Router) for the same route type it's rendered by.
+pub struct Redirect<R: Routable + Clone + PartialEq + 'static> {
+    link: ComponentLink<Self>,
+    props: RedirectProps<R>,
+}
+
+impl<R: Routable + Clone + PartialEq + 'static> Component for Redirect<R> {
+    type Message = ();
+    type Properties = RedirectProps<R>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, mut props: Self::Properties) -> ShouldRender {
+        std::mem::swap(&mut self.props, &mut props);
+        props != self.props
+    }
+
+    fn view(&self) -> Html {
+        html! {}
+    }
+
+    fn rendered(&mut self, _first_render: bool) {
+        // navigating while the parent router is rendering would render it again right away
+        let path = match self.link.consume_context::<RouterContext<R>>() {
+            Some(context) => context.path(&self.props.to),
+            None => self.props.to.to_path(),
+        };
+        navigation::replace(&path);
+    }
+}
@@ -0,0 +1,534 @@
+//! Backends the router uses to read and change the current location.
+//!
+//! The router doesn't access the browser history API directly. It goes through the active
+//! [`History`], which is a [`BrowserHistory`] unless another one is set with [`set_history`].
+
+use crate::utils::base_url;
+use gloo::events::EventListener;
+use serde::Deserialize;
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::{Rc, Weak};
+use wasm_bindgen::JsValue;
+use web_sys::Event;
+use yew::Callback;
+
+/// A location in a [`History`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    path: String,
+    query: String,
+}
+
+impl Location {
+    /// Creates a location from a path which may be followed by a query string.
+    ///
+    /// An empty path is the same as `/`.
+    pub fn new(path_and_query: &str) -> Self {
+        let (path, query) = match path_and_query.find('?') {
+            Some(index) => (&path_and_query[..index], &path_and_query[index + 1..]),
+            None => (path_and_query, ""),
+        };
+        let path = if path.is_empty() { "/" } else { path };
+
+        Self {
+            path: path.to_string(),
+            query: query.to_string(),
+        }
+    }
+
+    /// The path of the location, like `/posts/1`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The query string of the location, without the leading `?`.
+    pub fn query_str(&self) -> &str {
+        &self.query
+    }
+
+    /// Deserializes the query string of the location.
+    pub fn query<T>(&self) -> Result<T, serde_urlencoded::de::Error>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        serde_urlencoded::from_str(&self.query)
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.query.is_empty() {
+            f.write_str(&self.path)
+        } else {
+            write!(f, "{}?{}", self.path, self.query)
+        }
+    }
+}
+
+/// Handle for a listener attached with [`History::listen`].
+///
+/// The listener is detached when the handle is dropped.
+pub struct HistoryListener {
+    // this exists so the guard is dropped when handle is dropped
+    #[allow(dead_code)]
+    guard: Box<dyn Any>,
+}
+
+impl HistoryListener {
+    /// Creates a handle which drops `guard` when it is dropped.
+    pub fn new(guard: impl Any) -> Self {
+        Self {
+            guard: Box::new(guard),
+        }
+    }
+}
+
+/// A backend storing the history of locations the application navigated to.
+///
+/// Paths passed to and returned by a history are paths of the application, which may be followed
+/// by a query string. How they are stored, for example below a base URL or in the URL hash, is up
+/// to the implementation.
+pub trait History {
+    /// Returns the current location.
+    fn location(&self) -> Location;
+
+    /// Adds a new entry for `path` and navigates to it.
+    fn push(&self, path: &str);
+
+    /// Replaces the current entry with an entry for `path` and navigates to it.
+    fn replace(&self, path: &str);
+
+    /// Navigates `delta` entries forward, or backward if `delta` is negative.
+    fn go(&self, delta: isize);
+
+    /// Navigates to the previous entry.
+    fn back(&self) {
+        self.go(-1);
+    }
+
+    /// Navigates to the next entry.
+    fn forward(&self) {
+        self.go(1);
+    }
+
+    /// Returns the value of the `href` attribute of a link to `path`.
+    fn href(&self, path: &str) -> String;
+
+    /// Adds a listener which is called every time the current location changes.
+    fn listen(&self, callback: Callback<()>) -> HistoryListener;
+}
+
+thread_local! {
+    static HISTORY: RefCell<Option<Rc<dyn History>>> = RefCell::new(None);
+}
+
+/// Sets the history used by the router.
+///
+/// This should be called before the [`Router`](crate::Router) is created.
+pub fn set_history(history: impl History + 'static) {
+    HISTORY.with(|it| *it.borrow_mut() = Some(Rc::new(history)));
+    crate::navigation::reset();
+}
+
+/// Returns the history used by the router.
+///
+/// This is a [`BrowserHistory`] unless another history was set with [`set_history`].
+pub fn history() -> Rc<dyn History> {
+    HISTORY.with(|it| {
+        Rc::clone(
+            it.borrow_mut()
+                .get_or_insert_with(|| Rc::new(BrowserHistory::new())),
+        )
+    })
+}
+
+fn dispatch_popstate() {
+    let event = Event::new("popstate").unwrap();
+    yew::utils::window()
+        .dispatch_event(&event)
+        .expect("dispatch");
+}
+
+fn listen_popstate(callback: Callback<()>) -> HistoryListener {
+    let listener = EventListener::new(&yew::utils::window(), "popstate", move |_| {
+        callback.emit(())
+    });
+    HistoryListener::new(listener)
+}
+
+/// A [`History`] using the browser history API.
+///
+/// Paths are stored as the path of the URL, below the base URL of the document if it has a
+/// `<base>` element.
+#[derive(Debug, Clone, Default)]
+pub struct BrowserHistory {}
+
+impl BrowserHistory {
+    /// Creates a new [`BrowserHistory`].
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn url(&self, path: &str) -> String {
+        match base_url() {
+            Some(base) => {
+                let url = format!("{}{}", base, path);
+                if url.is_empty() {
+                    "/".to_string()
+                } else {
+                    url
+                }
+            }
+            None => path.to_string(),
+        }
+    }
+}
+
+impl History for BrowserHistory {
+    fn location(&self) -> Location {
+        let location = yew::utils::window().location();
+        let pathname = location.pathname().unwrap();
+        let path = match base_url() {
+            Some(base) => pathname
+                .strip_prefix(&base)
+                .unwrap_or(&pathname)
+                .to_string(),
+            None => pathname,
+        };
+        let search = location.search().unwrap();
+
+        Location::new(&format!("{}{}", path, search))
+    }
+
+    fn push(&self, path: &str) {
+        yew::utils::window()
+            .history()
+            .expect("no history")
+            .push_state_with_url(&JsValue::NULL, "", Some(&self.url(path)))
+            .expect("push history");
+        dispatch_popstate();
+    }
+
+    fn replace(&self, path: &str) {
+        yew::utils::window()
+            .history()
+            .expect("no history")
+            .replace_state_with_url(&JsValue::NULL, "", Some(&self.url(path)))
+            .expect("replace history");
+        dispatch_popstate();
+    }
+
+    fn go(&self, delta: isize) {
+        yew::utils::window()
+            .history()
+            .expect("no history")
+            .go_with_delta(delta as i32)
+            .expect("go history");
+    }
+
+    fn href(&self, path: &str) -> String {
+        self.url(path)
+    }
+
+    fn listen(&self, callback: Callback<()>) -> HistoryListener {
+        listen_popstate(callback)
+    }
+}
+
+/// A [`History`] storing paths in the hash of the URL, like `/index.html#/posts/1`.
+///
+/// This works with servers which can't serve the application for every path, like static hosts.
+#[derive(Debug, Clone, Default)]
+pub struct HashHistory {}
+
+impl HashHistory {
+    /// Creates a new [`HashHistory`].
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl History for HashHistory {
+    fn location(&self) -> Location {
+        let hash = yew::utils::window().location().hash().unwrap();
+        Location::new(hash.strip_prefix('#').unwrap_or(&hash))
+    }
+
+    fn push(&self, path: &str) {
+        yew::utils::window()
+            .history()
+            .expect("no history")
+            .push_state_with_url(&JsValue::NULL, "", Some(&self.href(path)))
+            .expect("push history");
+        dispatch_popstate();
+    }
+
+    fn replace(&self, path: &str) {
+        yew::utils::window()
+            .history()
+            .expect("no history")
+            .replace_state_with_url(&JsValue::NULL, "", Some(&self.href(path)))
+            .expect("replace history");
+        dispatch_popstate();
+    }
+
+    fn go(&self, delta: isize) {
+        yew::utils::window()
+            .history()
+            .expect("no history")
+            .go_with_delta(delta as i32)
+            .expect("go history");
+    }
+
+    fn href(&self, path: &str) -> String {
+        format!("#{}", path)
+    }
+
+    fn listen(&self, callback: Callback<()>) -> HistoryListener {
+        // navigating to another hash fires `popstate` as well
+        listen_popstate(callback)
+    }
+}
+
+#[derive(Default)]
+struct MemoryState {
+    entries: Vec<Location>,
+    index: usize,
+    listeners: Vec<Weak<Callback<()>>>,
+}
+
+/// A [`History`] keeping its entries in memory.
+///
+/// It doesn't access any browser API, so it can be used outside of the browser, for example in
+/// unit tests. Clones of a [`MemoryHistory`] share the same entries.
+#[derive(Clone)]
+pub struct MemoryHistory {
+    state: Rc<RefCell<MemoryState>>,
+}
+
+impl MemoryHistory {
+    /// Creates a new [`MemoryHistory`] with a single entry for `/`.
+    pub fn new() -> Self {
+        Self::with_entries(vec!["/"])
+    }
+
+    /// Creates a new [`MemoryHistory`] with the given entries, positioned at the last one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entries` is empty.
+    pub fn with_entries<'a>(entries: impl IntoIterator<Item = &'a str>) -> Self {
+        let entries = entries.into_iter().map(Location::new).collect::<Vec<_>>();
+        assert!(!entries.is_empty(), "a history needs at least one entry");
+        let index = entries.len() - 1;
+
+        Self {
+            state: Rc::new(RefCell::new(MemoryState {
+                entries,
+                index,
+                listeners: Vec::new(),
+            })),
+        }
+    }
+
+    /// Returns the entries of the history.
+    pub fn entries(&self) -> Vec<Location> {
+        self.state.borrow().entries.clone()
+    }
+
+    /// Returns the index of the current entry.
+    pub fn index(&self) -> usize {
+        self.state.borrow().index
+    }
+
+    fn notify(&self) {
+        let listeners = {
+            let mut state = self.state.borrow_mut();
+            state.listeners.retain(|it| it.strong_count() > 0);
+            state
+                .listeners
+                .iter()
+                .filter_map(Weak::upgrade)
+                .collect::<Vec<_>>()
+        };
+        // the state is no longer borrowed, so listeners can access the history
+        for listener in listeners {
+            listener.emit(());
+        }
+    }
+}
+
+impl Default for MemoryHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for MemoryHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct("MemoryHistory")
+            .field("entries", &state.entries)
+            .field("index", &state.index)
+            .finish()
+    }
+}
+
+impl History for MemoryHistory {
+    fn location(&self) -> Location {
+        let state = self.state.borrow();
+        state.entries[state.index].clone()
+    }
+
+    fn push(&self, path: &str) {
+        {
+            let mut state = self.state.borrow_mut();
+            let index = state.index + 1;
+            state.entries.truncate(index);
+            state.entries.push(Location::new(path));
+            state.index = index;
+        }
+        self.notify();
+    }
+
+    fn replace(&self, path: &str) {
+        {
+            let mut state = self.state.borrow_mut();
+            let index = state.index;
+            state.entries[index] = Location::new(path);
+        }
+        self.notify();
+    }
+
+    fn go(&self, delta: isize) {
+        let changed = {
+            let mut state = self.state.borrow_mut();
+            let index = state.index as isize + delta;
+            // like the browser, do nothing if there's no such entry
+            if delta == 0 || index < 0 || index >= state.entries.len() as isize {
+                false
+            } else {
+                state.index = index as usize;
+                true
+            }
+        };
+        if changed {
+            self.notify();
+        }
+    }
+
+    fn href(&self, path: &str) -> String {
+        path.to_string()
+    }
+
+    fn listen(&self, callback: Callback<()>) -> HistoryListener {
+        let callback = Rc::new(callback);
+        self.state
+            .borrow_mut()
+            .listeners
+            .push(Rc::downgrade(&callback));
+        HistoryListener::new(callback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn location_splits_query() {
+        let location = Location::new("/posts?page=2");
+        assert_eq!(location.path(), "/posts");
+        assert_eq!(location.query_str(), "page=2");
+        assert_eq!(location.to_string(), "/posts?page=2");
+
+        let location = Location::new("");
+        assert_eq!(location.path(), "/");
+        assert_eq!(location.query_str(), "");
+    }
+
+    #[test]
+    fn memory_history_navigates() {
+        let history = MemoryHistory::new();
+        history.push("/a");
+        history.push("/b?c=d");
+        assert_eq!(history.location(), Location::new("/b?c=d"));
+        assert_eq!(history.entries().len(), 3);
+
+        history.back();
+        assert_eq!(history.location().path(), "/a");
+        history.go(-5);
+        assert_eq!(history.location().path(), "/a");
+        history.forward();
+        assert_eq!(history.location().path(), "/b");
+
+        history.go(-2);
+        history.push("/e");
+        assert_eq!(history.entries().len(), 2);
+        history.replace("/f");
+        assert_eq!(history.entries().len(), 2);
+        assert_eq!(history.index(), 1);
+        assert_eq!(history.location().path(), "/f");
+    }
+
+    #[test]
+    fn memory_history_notifies_listeners() {
+        let history = MemoryHistory::with_entries(vec!["/a", "/b"]);
+        let calls = Rc::new(Cell::new(0));
+        let listener = {
+            let calls = Rc::clone(&calls);
+            let history = history.clone();
+            history.clone().listen(Callback::from(move |_| {
+                assert_eq!(history.location().path(), "/a");
+                calls.set(calls.get() + 1);
+            }))
+        };
+
+        history.back();
+        history.back();
+        assert_eq!(calls.get(), 1);
+
+        drop(listener);
+        history.forward();
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn push_route_uses_active_history() {
+        use crate::Routable;
+
+        #[derive(Debug, Clone, PartialEq, Routable)]
+        enum Routes {
+            #[at("/")]
+            Home,
+            #[at("/posts/:id")]
+            Post { id: u32 },
+        }
+
+        #[derive(serde::Serialize)]
+        struct Query {
+            page: u32,
+        }
+
+        let history = MemoryHistory::new();
+        set_history(history.clone());
+
+        crate::push_route(Routes::Post { id: 1 });
+        assert_eq!(
+            Routes::recognize(history.location().path()),
+            Some(Routes::Post { id: 1 })
+        );
+
+        crate::push_route_with_query(Routes::Home, Query { page: 2 }).unwrap();
+        assert_eq!(history.location().to_string(), "/?page=2");
+        assert_eq!(
+            crate::parse_query::<std::collections::HashMap<String, u32>>()
+                .unwrap()
+                .get("page"),
+            Some(&2)
+        );
+    }
+}
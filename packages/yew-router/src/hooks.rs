@@ -0,0 +1,63 @@
+//! Hooks to access the router from function components.
+
+use crate::history::HistoryListener;
+use crate::navigation;
+use serde::Deserialize;
+use yew::functional::use_hook;
+use yew::Callback;
+
+struct UseQuery {
+    query: String,
+    listener: Option<HistoryListener>,
+}
+
+/// This hook deserializes the query string of the current location, like
+/// [`parse_query`](crate::parse_query).
+///
+/// The component is re-rendered when the query string changes, but not when only the path does.
+///
+/// # Example
+/// ```rust
+/// # use serde::Deserialize;
+/// # use yew::prelude::*;
+/// # use yew_router::use_query;
+/// #
+/// #[derive(Deserialize)]
+/// struct SearchQuery {
+///     q: String,
+/// }
+///
+/// #[function_component(Search)]
+/// fn search() -> Html {
+///     match use_query::<SearchQuery>() {
+///         Ok(query) => html! { <p>{ format!("Results for {}", query.q) }</p> },
+///         Err(_) => html! { <p>{ "Nothing to search" }</p> },
+///     }
+/// }
+/// ```
+pub fn use_query<T>() -> Result<T, serde_urlencoded::de::Error>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    use_hook(
+        || UseQuery {
+            query: String::new(),
+            listener: None,
+        },
+        |hook, updater| {
+            let location = navigation::location();
+            hook.query = location.query_str().to_string();
+            if hook.listener.is_none() {
+                hook.listener = Some(navigation::listen(Callback::from(move |_| {
+                    updater.callback(|hook: &mut UseQuery| {
+                        hook.query != navigation::location().query_str()
+                    })
+                })));
+            }
+
+            location.query()
+        },
+        // The listener holds the state of the hook, drop it to not leak the state
+        |hook| hook.listener = None,
+    )
+}
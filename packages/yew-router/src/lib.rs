@@ -1,6 +1,10 @@
 //! Provides routing faculties using the browser history API to build
 //! Single Page Applications (SPAs) using [Yew web framework](https://yew.rs).
 //!
+//! The history API is accessed through the active [`History`](history::History), so routes can
+//! also be stored in the URL hash with [`HashHistory`] or in memory with [`MemoryHistory`]. See
+//! the [`history`] module for more information.
+//!
 //! # Usage
 //!
 //! ```rust
@@ -59,6 +63,9 @@ extern crate self as yew_router;
 #[path = "macro_helpers.rs"]
 pub mod __macro;
 pub mod components;
+pub mod history;
+pub mod hooks;
+pub mod navigation;
 mod routable;
 pub mod router;
 mod service;
@@ -66,15 +73,19 @@ pub(crate) mod utils;
 
 pub use service::*;
 
+pub use history::{history, set_history, BrowserHistory, HashHistory, MemoryHistory};
+pub use hooks::use_query;
+pub use navigation::{add_async_guard, add_guard, prompt_on_leave, GuardResult};
+
 pub use routable::Routable;
-pub use router::{RenderFn, Router};
+pub use router::{RenderFn, Router, RouterContext};
 
 pub mod prelude {
     //! Prelude module to be imported when working with `yew-router`.
     //!
     //! This module re-exports the frequently used types from the crate.
 
-    pub use crate::components::Link;
+    pub use crate::components::{Link, Redirect};
     #[doc(no_inline)]
     pub use crate::Routable;
     pub use crate::Router;
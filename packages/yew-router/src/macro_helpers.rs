@@ -1,24 +1,18 @@
-use crate::utils::{base_url, strip_slash_suffix};
+use crate::utils::strip_slash_suffix;
 use crate::Routable;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub use serde_urlencoded::ser::Error as QueryError;
 
 // re-export Router because the macro needs to access it
 pub type Router = route_recognizer::Router<String>;
 
 /// Build a `route_recognizer::Router` from a `Routable` type.
 pub fn build_router<R: Routable>() -> Router {
-    let base = base_url();
     let mut router = Router::new();
     R::routes().iter().for_each(|path| {
-        match &base {
-            Some(base) => {
-                let route = format!("{}{}", base, path);
-                let route = strip_slash_suffix(&route);
-                router.add(route, path.to_string());
-            }
-            _ => {
-                router.add(path, path.to_string());
-            }
-        };
+        router.add(path, path.to_string());
     });
 
     router
@@ -26,11 +20,37 @@ pub fn build_router<R: Routable>() -> Router {
 
 /// Use a `route_recognizer::Router` to build the route of a `Routable`
 pub fn recognize_with_router<R: Routable>(router: &Router, pathname: &str) -> Option<R> {
+    let (pathname, query) = match pathname.find('?') {
+        Some(index) => (&pathname[..index], &pathname[index + 1..]),
+        None => (pathname, ""),
+    };
     let pathname = strip_slash_suffix(pathname);
     let matched = router.recognize(pathname);
 
     match matched {
-        Ok(matched) => R::from_path(matched.handler(), &matched.params().into_iter().collect()),
+        Ok(matched) => R::from_path_with_query(
+            matched.handler(),
+            &matched.params().into_iter().collect(),
+            query,
+        ),
         Err(_) => R::not_found_route(),
     }
 }
+
+/// Serializes the field storing the query of a route.
+pub fn serialize_query<T: Serialize + ?Sized>(query: &T) -> Result<String, QueryError> {
+    serde_urlencoded::to_string(query)
+}
+
+/// Serializes the field storing the query of a route, which is omitted if it can't be serialized.
+pub fn serialize_query_or_log<T: Serialize + ?Sized>(query: &T) -> String {
+    serialize_query(query).unwrap_or_else(|error| {
+        weblog::console_error!(format!("the query of the route is omitted: {}", error));
+        String::new()
+    })
+}
+
+/// Deserializes the field storing the query of a route.
+pub fn deserialize_query<T: DeserializeOwned>(query: &str) -> Option<T> {
+    serde_urlencoded::from_str(query).ok()
+}
@@ -0,0 +1,482 @@
+//! Navigation between locations, and guards which can cancel or redirect navigations.
+//!
+//! Every navigation of the router, like [`push_route`](crate::push_route) or a click on a
+//! [`Link`](crate::components::Link), goes through the guards added with [`add_guard`] and
+//! [`add_async_guard`] before the [`History`](crate::history::History) is changed. Navigations the router doesn't make,
+//! like the back and forward buttons of the browser, change the history first. If a guard
+//! cancels them, the previous location is pushed again.
+//!
+//! The [`Router`](crate::Router) only renders a location once every guard allowed it.
+
+use crate::history::{history, HistoryListener, Location};
+use crate::Routable;
+use gloo::events::{EventListener, EventListenerOptions};
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::BeforeUnloadEvent;
+use yew::Callback;
+
+/// The maximum number of redirects of a single navigation, after which it is cancelled.
+const MAX_REDIRECTS: usize = 16;
+
+/// How a [`Navigation`] changes the history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavigationKind {
+    /// A new entry is added.
+    Push,
+    /// The current entry is replaced.
+    Replace,
+    /// The history already moved to another entry, for example with the back button.
+    Pop,
+}
+
+/// A navigation from a location to another, which is passed to guards.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Navigation {
+    from: Location,
+    to: Location,
+    kind: NavigationKind,
+}
+
+impl Navigation {
+    /// The location rendered by the router before the navigation.
+    pub fn from(&self) -> &Location {
+        &self.from
+    }
+
+    /// The location the navigation leads to.
+    pub fn to(&self) -> &Location {
+        &self.to
+    }
+
+    /// How the navigation changes the history.
+    pub fn kind(&self) -> NavigationKind {
+        self.kind
+    }
+}
+
+/// What a guard decides about a [`Navigation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuardResult {
+    /// The navigation is allowed, unless another guard decides otherwise.
+    Allow,
+    /// The navigation is cancelled.
+    Cancel,
+    /// The navigation leads to the given path instead.
+    Redirect(String),
+}
+
+impl GuardResult {
+    /// Redirects the navigation to `route`.
+    pub fn redirect(route: impl Routable) -> Self {
+        Self::Redirect(route.to_path())
+    }
+}
+
+type GuardFuture = Pin<Box<dyn Future<Output = GuardResult>>>;
+
+#[derive(Clone)]
+enum Guard {
+    Sync(Rc<dyn Fn(&Navigation) -> GuardResult>),
+    Async(Rc<dyn Fn(&Navigation) -> GuardFuture>),
+}
+
+#[derive(Default)]
+struct Navigator {
+    guards: Vec<(usize, Guard)>,
+    next_guard_id: usize,
+    /// The location rendered by the router, which every guard allowed
+    location: Option<Location>,
+    /// The location the history is changed to by an allowed navigation
+    expected: Option<Location>,
+    /// Incremented for every navigation, so the guards of a previous one can't complete it
+    generation: u32,
+    listeners: Vec<Weak<Callback<()>>>,
+    history_listener: Option<HistoryListener>,
+}
+
+thread_local! {
+    static NAVIGATOR: RefCell<Navigator> = RefCell::new(Navigator::default());
+}
+
+fn with_navigator<T>(f: impl FnOnce(&mut Navigator) -> T) -> T {
+    NAVIGATOR.with(|navigator| f(&mut navigator.borrow_mut()))
+}
+
+/// Starts listening to the history, if it's not done yet, and returns the current location.
+fn init() -> Location {
+    if let Some(location) = with_navigator(|navigator| navigator.location.clone()) {
+        return location;
+    }
+
+    let history = history();
+    let history_listener = history.listen(Callback::from(|_| on_history_change()));
+    let location = history.location();
+    with_navigator(|navigator| {
+        navigator.history_listener = Some(history_listener);
+        navigator.location = Some(location.clone());
+    });
+    location
+}
+
+/// Forgets the location of the previous history after [`set_history`](crate::set_history).
+pub(crate) fn reset() {
+    let history_listener = with_navigator(|navigator| {
+        navigator.location = None;
+        navigator.expected = None;
+        navigator.generation = navigator.generation.wrapping_add(1);
+        navigator.history_listener.take()
+    });
+    drop(history_listener);
+}
+
+/// Returns the location rendered by the router.
+///
+/// While the guards of a navigation are running, this is the location before the navigation,
+/// even if the history already changed.
+pub fn location() -> Location {
+    init()
+}
+
+/// Adds a listener which is called every time the location rendered by the router changes.
+pub fn listen(callback: Callback<()>) -> HistoryListener {
+    init();
+    let callback = Rc::new(callback);
+    with_navigator(|navigator| navigator.listeners.push(Rc::downgrade(&callback)));
+    HistoryListener::new(callback)
+}
+
+/// Navigates to `path` by adding a new history entry, if the guards allow it.
+pub fn push(path: &str) {
+    navigate(Location::new(path), NavigationKind::Push, 0);
+}
+
+/// Navigates to `path` by replacing the current history entry, if the guards allow it.
+pub fn replace(path: &str) {
+    navigate(Location::new(path), NavigationKind::Replace, 0);
+}
+
+fn commit(location: Location) {
+    let listeners = with_navigator(|navigator| {
+        if navigator.location.as_ref() == Some(&location) {
+            return vec![];
+        }
+        navigator.location = Some(location);
+        navigator.listeners.retain(|it| it.strong_count() > 0);
+        navigator
+            .listeners
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect::<Vec<_>>()
+    });
+    // the navigator is no longer borrowed, so listeners can access it
+    for listener in listeners {
+        listener.emit(());
+    }
+}
+
+/// Changes the history to `location`, which will be committed once the history notifies us.
+fn apply(location: Location, kind: NavigationKind) {
+    with_navigator(|navigator| navigator.expected = Some(location.clone()));
+    let history = history();
+    match kind {
+        NavigationKind::Push => history.push(&location.to_string()),
+        NavigationKind::Replace | NavigationKind::Pop => history.replace(&location.to_string()),
+    }
+}
+
+fn navigate(to: Location, kind: NavigationKind, redirects: usize) {
+    let from = init();
+    let navigation = Navigation {
+        from: from.clone(),
+        to: to.clone(),
+        kind,
+    };
+    run_guards(navigation, move |result| match result {
+        GuardResult::Allow => {
+            if kind == NavigationKind::Pop {
+                commit(to);
+            } else {
+                apply(to, kind);
+            }
+        }
+        GuardResult::Cancel => {
+            if kind == NavigationKind::Pop {
+                // the history already moved, so go back to the rendered location
+                apply(from, NavigationKind::Push);
+            }
+        }
+        GuardResult::Redirect(path) => {
+            if redirects >= MAX_REDIRECTS {
+                weblog::console_error!("too many redirects, the navigation is cancelled");
+                return;
+            }
+            let kind = match kind {
+                NavigationKind::Push => NavigationKind::Push,
+                NavigationKind::Replace | NavigationKind::Pop => NavigationKind::Replace,
+            };
+            navigate(Location::new(&path), kind, redirects + 1);
+        }
+    });
+}
+
+fn on_history_change() {
+    let location = history().location();
+    let expected = with_navigator(|navigator| {
+        if navigator.expected.as_ref() == Some(&location) {
+            navigator.expected = None;
+            true
+        } else {
+            false
+        }
+    });
+
+    if expected {
+        commit(location);
+    } else if with_navigator(|navigator| navigator.location.as_ref() != Some(&location)) {
+        navigate(location, NavigationKind::Pop, 0);
+    }
+}
+
+/// Runs the guards for `navigation` in order, until one of them doesn't allow it, and calls
+/// `done` with the result if no other navigation started in the meantime.
+fn run_guards(navigation: Navigation, done: impl FnOnce(GuardResult) + 'static) {
+    let (guards, generation) = with_navigator(|navigator| {
+        navigator.generation = navigator.generation.wrapping_add(1);
+        let guards = navigator
+            .guards
+            .iter()
+            .map(|(_, guard)| guard.clone())
+            .collect::<Vec<_>>();
+        (guards, navigator.generation)
+    });
+    let done = move |result| {
+        if with_navigator(|navigator| navigator.generation) == generation {
+            done(result);
+        }
+    };
+    run_guards_from(navigation, guards, done);
+}
+
+fn run_guards_from(
+    navigation: Navigation,
+    mut guards: Vec<Guard>,
+    done: impl FnOnce(GuardResult) + 'static,
+) {
+    while !guards.is_empty() {
+        match guards.remove(0) {
+            Guard::Sync(guard) => match guard(&navigation) {
+                GuardResult::Allow => {}
+                result => return done(result),
+            },
+            Guard::Async(guard) => {
+                let future = guard(&navigation);
+                spawn_local(async move {
+                    match future.await {
+                        GuardResult::Allow => run_guards_from(navigation, guards, done),
+                        result => done(result),
+                    }
+                });
+                return;
+            }
+        }
+    }
+    done(GuardResult::Allow);
+}
+
+/// Handle for a guard added with [`add_guard`] or [`add_async_guard`].
+///
+/// The guard is removed when the handle is dropped.
+#[must_use = "the guard is removed when the handle is dropped"]
+pub struct GuardHandle {
+    id: usize,
+}
+
+impl Drop for GuardHandle {
+    fn drop(&mut self) {
+        let id = self.id;
+        // the navigator may already be destroyed if the handle is stored in a thread local
+        let _ = NAVIGATOR.try_with(|navigator| {
+            navigator
+                .borrow_mut()
+                .guards
+                .retain(|(guard_id, _)| *guard_id != id)
+        });
+    }
+}
+
+fn add(guard: Guard) -> GuardHandle {
+    with_navigator(|navigator| {
+        let id = navigator.next_guard_id;
+        navigator.next_guard_id += 1;
+        navigator.guards.push((id, guard));
+        GuardHandle { id }
+    })
+}
+
+/// Adds a guard which is called before every navigation.
+///
+/// Guards are called in the order they were added. A navigation only happens if every guard
+/// returns [`GuardResult::Allow`].
+///
+/// # Example
+///
+/// ```rust
+/// # use yew_router::prelude::*;
+/// # use yew_router::navigation::GuardResult;
+/// # fn is_logged_in() -> bool { true }
+/// #[derive(Debug, Clone, Copy, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/login")]
+///     Login,
+///     #[at("/account")]
+///     Account,
+/// }
+///
+/// let guard = yew_router::add_guard(|navigation| {
+///     if navigation.to().path() == Route::Account.to_path() && !is_logged_in() {
+///         GuardResult::redirect(Route::Login)
+///     } else {
+///         GuardResult::Allow
+///     }
+/// });
+/// ```
+pub fn add_guard(guard: impl Fn(&Navigation) -> GuardResult + 'static) -> GuardHandle {
+    add(Guard::Sync(Rc::new(guard)))
+}
+
+/// Adds a guard which is called before every navigation, and decides asynchronously.
+///
+/// The router keeps rendering the previous location until the future resolves. If another
+/// navigation starts in the meantime, the result is ignored.
+pub fn add_async_guard<F, Fut>(guard: F) -> GuardHandle
+where
+    F: Fn(&Navigation) -> Fut + 'static,
+    Fut: Future<Output = GuardResult> + 'static,
+{
+    add(Guard::Async(Rc::new(move |navigation: &Navigation| {
+        Box::pin(guard(navigation)) as GuardFuture
+    })))
+}
+
+/// Handle for the prompt added with [`prompt_on_leave`].
+///
+/// The prompt is removed when the handle is dropped.
+#[must_use = "the prompt is removed when the handle is dropped"]
+pub struct LeavePrompt {
+    #[allow(dead_code)] // only exists to remove the guard when dropped
+    guard: GuardHandle,
+    #[allow(dead_code)] // only exists to drop listener when dropped
+    listener: EventListener,
+}
+
+/// Asks the user to confirm leaving the current location, for example while a form has unsaved
+/// changes.
+///
+/// Navigations of the router show `message` in a confirmation dialog and are cancelled unless the
+/// user confirms them. When the page itself is closed or reloaded, the `beforeunload` dialog of
+/// the browser is shown, which usually doesn't include the message.
+pub fn prompt_on_leave(message: impl Into<String>) -> LeavePrompt {
+    let message = message.into();
+    let listener = {
+        let message = message.clone();
+        EventListener::new_with_options(
+            &yew::utils::window(),
+            "beforeunload",
+            EventListenerOptions::enable_prevent_default(),
+            move |event| {
+                let event = event.unchecked_ref::<BeforeUnloadEvent>();
+                event.prevent_default();
+                event.set_return_value(&message);
+            },
+        )
+    };
+    let guard = add_guard(move |navigation| {
+        if navigation.from().path() == navigation.to().path() {
+            return GuardResult::Allow;
+        }
+        match yew::utils::window().confirm_with_message(&message) {
+            Ok(true) => GuardResult::Allow,
+            _ => GuardResult::Cancel,
+        }
+    });
+
+    LeavePrompt { guard, listener }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::{History, MemoryHistory};
+    use crate::set_history;
+    use std::cell::Cell;
+
+    #[test]
+    fn guards_cancel_and_redirect_navigations() {
+        let history = MemoryHistory::new();
+        set_history(history.clone());
+
+        let cancel = add_guard(|navigation| {
+            if navigation.to().path() == "/blocked" {
+                GuardResult::Cancel
+            } else {
+                GuardResult::Allow
+            }
+        });
+        let redirect = add_guard(|navigation| {
+            if navigation.to().path() == "/old" {
+                GuardResult::Redirect("/new".to_string())
+            } else {
+                GuardResult::Allow
+            }
+        });
+
+        push("/blocked");
+        assert_eq!(history.location().path(), "/");
+        assert_eq!(location().path(), "/");
+
+        push("/old");
+        assert_eq!(history.location().path(), "/new");
+        assert_eq!(location().path(), "/new");
+        assert_eq!(history.entries().len(), 2);
+
+        drop(cancel);
+        drop(redirect);
+        push("/blocked");
+        assert_eq!(location().path(), "/blocked");
+    }
+
+    #[test]
+    fn cancelled_pops_restore_the_location() {
+        let history = MemoryHistory::with_entries(vec!["/a", "/form"]);
+        set_history(history.clone());
+
+        let renders = Rc::new(Cell::new(0));
+        let _listener = {
+            let renders = Rc::clone(&renders);
+            listen(Callback::from(move |_| renders.set(renders.get() + 1)))
+        };
+        let guard = add_guard(|navigation| {
+            assert_eq!(navigation.kind(), NavigationKind::Pop);
+            if navigation.from().path() == "/form" {
+                GuardResult::Cancel
+            } else {
+                GuardResult::Allow
+            }
+        });
+
+        history.back();
+        assert_eq!(history.location().path(), "/form");
+        assert_eq!(location().path(), "/form");
+        assert_eq!(renders.get(), 0);
+
+        drop(guard);
+        history.back();
+        assert_eq!(location().path(), "/a");
+        assert_eq!(renders.get(), 1);
+    }
+}
@@ -17,9 +17,29 @@ pub trait Routable: Sized + Clone {
     /// Converts path to an instance of the routes enum.
     fn from_path(path: &str, params: &HashMap<&str, &str>) -> Option<Self>;
 
+    /// Converts path and the query string of the URL to an instance of the routes enum.
+    ///
+    /// `query` is the query string without the leading `?`. This is what the router calls, and by
+    /// default it ignores the query and calls [`from_path`](Self::from_path).
+    fn from_path_with_query(path: &str, params: &HashMap<&str, &str>, query: &str) -> Option<Self> {
+        let _ = query;
+        Self::from_path(path, params)
+    }
+
     /// Converts the route to a string that can passed to the history API.
+    ///
+    /// The path is followed by the query string if the route has a query. A query which can't be
+    /// serialized is omitted, and an error is logged.
     fn to_path(&self) -> String;
 
+    /// Converts the route to a string like [`to_path`](Self::to_path), but returns an error if
+    /// the query of the route can't be serialized.
+    ///
+    /// By default this calls [`to_path`](Self::to_path).
+    fn try_to_path(&self) -> Result<String, serde_urlencoded::ser::Error> {
+        Ok(self.to_path())
+    }
+
     /// Lists all the available routes
     fn routes() -> Vec<&'static str>;
 
@@ -31,9 +51,167 @@ pub trait Routable: Sized + Clone {
     /// This is the cached result of [`recognize`]
     fn current_route() -> Option<Self>;
 
-    /// Match a route based on the path
+    /// Match a route based on the path, which may be followed by a query string
     fn recognize(pathname: &str) -> Option<Self>;
 
     /// Called when [`Router`](crate::Router) is destroyed.
     fn cleanup() {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Routable;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Routable)]
+    enum Routes {
+        #[at("/")]
+        Home,
+        #[at("/posts/:id")]
+        Post(u32),
+        #[at("/pages/:page?")]
+        Pages { page: Option<u32> },
+        #[at("/files/*path")]
+        File { path: String },
+        #[at("/admin/*")]
+        Admin(AdminRoutes),
+        #[at("/orgs/:org/*")]
+        Org(String, AdminRoutes),
+        #[at("/search")]
+        Search(#[query] SearchQuery),
+        #[at("/tags/:tag")]
+        Tag(#[query] PageQuery, String),
+        #[at("authors/:name")]
+        Author { name: String },
+        #[not_found]
+        #[at("/404")]
+        NotFound,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Routable)]
+    enum AdminRoutes {
+        #[at("/")]
+        Home,
+        #[at("/users/:id")]
+        User { id: u32 },
+        #[at("/logs")]
+        Logs {
+            #[query]
+            query: PageQuery,
+        },
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct SearchQuery {
+        q: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct PageQuery {
+        page: Option<u32>,
+    }
+
+    fn assert_round_trip(path: &str, route: Routes) {
+        assert_eq!(Routes::recognize(path), Some(route.clone()));
+        assert_eq!(route.to_path(), path);
+    }
+
+    #[test]
+    fn routes_round_trip() {
+        assert_round_trip("/", Routes::Home);
+        assert_round_trip("/posts/1", Routes::Post(1));
+        assert_round_trip("/pages", Routes::Pages { page: None });
+        assert_round_trip("/pages/2", Routes::Pages { page: Some(2) });
+        assert_round_trip(
+            "/files/a/b.txt",
+            Routes::File {
+                path: "a/b.txt".to_string(),
+            },
+        );
+        assert_round_trip("/admin", Routes::Admin(AdminRoutes::Home));
+        assert_round_trip("/admin/users/3", Routes::Admin(AdminRoutes::User { id: 3 }));
+        assert_round_trip(
+            "/orgs/yew/users/4",
+            Routes::Org("yew".to_string(), AdminRoutes::User { id: 4 }),
+        );
+        assert_round_trip(
+            "/authors/ferris",
+            Routes::Author {
+                name: "ferris".to_string(),
+            },
+        );
+    }
+
+    #[test]
+    fn queries_round_trip() {
+        assert_round_trip(
+            "/search?q=yew+router",
+            Routes::Search(SearchQuery {
+                q: "yew router".to_string(),
+            }),
+        );
+        assert_round_trip(
+            "/tags/rust",
+            Routes::Tag(PageQuery { page: None }, "rust".to_string()),
+        );
+        assert_round_trip(
+            "/tags/rust?page=2",
+            Routes::Tag(PageQuery { page: Some(2) }, "rust".to_string()),
+        );
+        assert_round_trip(
+            "/admin/logs?page=3",
+            Routes::Admin(AdminRoutes::Logs {
+                query: PageQuery { page: Some(3) },
+            }),
+        );
+    }
+
+    #[test]
+    fn queries_are_ignored_by_routes_without_query() {
+        assert_eq!(Routes::recognize("/posts/1?page=2"), Some(Routes::Post(1)));
+        assert_eq!(
+            Routes::recognize("/admin/?page=2"),
+            Some(Routes::Admin(AdminRoutes::Home))
+        );
+        // the query can't be deserialized
+        assert_eq!(Routes::recognize("/search"), None);
+    }
+
+    #[test]
+    fn unencodable_queries_are_errors() {
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct NestedQuery {
+            search: SearchQuery,
+        }
+
+        #[derive(Debug, Clone, PartialEq, Routable)]
+        enum SearchRoutes {
+            #[at("/search")]
+            Search(#[query] NestedQuery),
+        }
+
+        #[derive(Debug, Clone, PartialEq, Routable)]
+        enum OuterRoutes {
+            #[at("/outer/*")]
+            Outer(SearchRoutes),
+        }
+
+        let route = SearchRoutes::Search(NestedQuery {
+            search: SearchQuery {
+                q: "yew".to_string(),
+            },
+        });
+        assert!(route.try_to_path().is_err());
+        assert!(OuterRoutes::Outer(route).try_to_path().is_err());
+    }
+
+    #[test]
+    fn unmatched_routes_are_not_found() {
+        assert_eq!(Routes::recognize("/nope"), Some(Routes::NotFound));
+        assert_eq!(
+            Routes::recognize("/admin/"),
+            Some(Routes::Admin(AdminRoutes::Home))
+        );
+        assert_eq!(Routes::recognize("/admin/users/x"), None);
+    }
+}
@@ -1,7 +1,10 @@
 //! Router Component.
 
-use crate::Routable;
-use gloo::events::EventListener;
+use crate::history::HistoryListener;
+use crate::utils::{join_path, strip_query};
+use crate::{navigation, Routable};
+use std::fmt;
+use std::marker::PhantomData;
 use std::rc::Rc;
 use yew::prelude::*;
 
@@ -52,6 +55,65 @@ impl<R> PartialEq for RouterProps<R> {
     }
 }
 
+/// Context provided by a [`Router`] to the components it renders.
+///
+/// The routes of a router nested in the route of another router are relative to the path matched
+/// by the parent, so [`Link`](crate::components::Link)s use this context to build the path of
+/// their route.
+pub struct RouterContext<R> {
+    base: String,
+    _route: PhantomData<R>,
+}
+
+impl<R> RouterContext<R> {
+    /// Returns the path the routes of the router are relative to.
+    ///
+    /// This is empty unless the router is nested in another router.
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+}
+
+impl<R: Routable> RouterContext<R> {
+    /// Returns the path of `route`, which can be passed to the [`History`](crate::history::History).
+    pub fn path(&self, route: &R) -> String {
+        join_path(&self.base, &route.to_path())
+    }
+
+    /// Returns the path of `route` like [`path`](Self::path), but returns an error if the query
+    /// of the route can't be serialized.
+    pub fn try_path(&self, route: &R) -> Result<String, serde_urlencoded::ser::Error> {
+        Ok(join_path(&self.base, &route.try_to_path()?))
+    }
+}
+
+impl<R> Clone for RouterContext<R> {
+    fn clone(&self) -> Self {
+        Self {
+            base: self.base.clone(),
+            _route: PhantomData,
+        }
+    }
+}
+
+impl<R> PartialEq for RouterContext<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+    }
+}
+
+impl<R> fmt::Debug for RouterContext<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RouterContext")
+            .field("base", &self.base)
+            .finish()
+    }
+}
+
+/// The path matched by the innermost router, which nested routers are relative to.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct NestedRouterBase(String);
+
 #[doc(hidden)]
 pub enum Msg {
     ReRender,
@@ -64,10 +126,17 @@ pub enum Msg {
 /// Otherwise `html! {}` is rendered and a message is logged to console
 /// stating that no route can be matched.
 /// See the [crate level document][crate] for more information.
+///
+/// # Nested routers
+///
+/// A router rendered by the route of another router matches the remainder of the path after the
+/// path of that route. The route of the parent usually ends with a `*` wildcard, like
+/// `#[at("/settings/*")]`, to match every path the nested router handles.
 pub struct Router<R: Routable + 'static> {
+    link: ComponentLink<Self>,
     props: RouterProps<R>,
     #[allow(dead_code)] // only exists to drop listener on component drop
-    route_listener: EventListener,
+    route_listener: HistoryListener,
 }
 
 impl<R> Component for Router<R>
@@ -78,11 +147,10 @@ where
     type Properties = RouterProps<R>;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
-        let route_listener = EventListener::new(&yew::utils::window(), "popstate", move |_| {
-            link.send_message(Msg::ReRender)
-        });
+        let route_listener = navigation::listen(link.callback(|_| Msg::ReRender));
 
         Self {
+            link,
             props,
             route_listener,
         }
@@ -100,11 +168,35 @@ where
     }
 
     fn view(&self) -> Html {
-        let pathname = yew::utils::window().location().pathname().unwrap();
-        let route = R::recognize(&pathname);
+        let base = self
+            .link
+            .consume_context::<NestedRouterBase>()
+            .unwrap_or_default()
+            .0;
+        let location = navigation::location();
+        let route = match location.path().strip_prefix(base.as_str()) {
+            Some("") => R::recognize(&format!("/?{}", location.query_str())),
+            Some(path) if path.starts_with('/') => {
+                R::recognize(&format!("{}?{}", path, location.query_str()))
+            }
+            _ => None,
+        };
 
         match route {
-            Some(route) => (self.props.render.0)(&route),
+            Some(route) => {
+                let nested = NestedRouterBase(join_path(&base, strip_query(&route.to_path())));
+                let context = RouterContext {
+                    base,
+                    _route: PhantomData,
+                };
+                html! {
+                    <ContextProvider<RouterContext<R>> {context}>
+                        <ContextProvider<NestedRouterBase> context={nested}>
+                            { (self.props.render.0)(&route) }
+                        </ContextProvider<NestedRouterBase>>
+                    </ContextProvider<RouterContext<R>>>
+                }
+            }
             None => {
                 weblog::console_warn!("no route matched");
                 html! {}
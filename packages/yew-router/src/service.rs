@@ -1,19 +1,23 @@
-use crate::utils::base_url;
-use crate::Routable;
-use gloo::events::EventListener;
+use crate::history::HistoryListener;
+use crate::{navigation, Routable};
 use serde::{Deserialize, Serialize};
-use wasm_bindgen::JsValue;
-use web_sys::Event;
 use yew::Callback;
 
 /// Navigate to a specific route.
 pub fn push_route(route: impl Routable) {
-    push_impl(route.to_path())
+    navigation::push(&route.to_path())
+}
+
+/// Navigate to a specific route, replacing the current history entry.
+pub fn replace_route(route: impl Routable) {
+    navigation::replace(&route.to_path())
 }
 
 /// Navigate to a specific route with query parameters.
 ///
-/// This should be used in cases where [`Link`](crate::prelude::Link) is insufficient.
+/// This should be used in cases where [`Link`](crate::prelude::Link) is insufficient. An error is
+/// returned, and the navigation doesn't happen, if `query` or the query of the route can't be
+/// serialized.
 pub fn push_route_with_query<S>(
     route: impl Routable,
     query: S,
@@ -21,47 +25,22 @@ pub fn push_route_with_query<S>(
 where
     S: Serialize,
 {
-    let mut url = route.to_path();
+    let mut url = route.try_to_path()?;
     let query = serde_urlencoded::to_string(query)?;
     if !query.is_empty() {
         url.push_str(&format!("?{}", query));
     }
 
-    push_impl(url);
+    navigation::push(&url);
 
     Ok(())
 }
 
-fn push_impl(url: String) {
-    let history = yew::utils::window().history().expect("no history");
-    let base = base_url();
-    let path = match base {
-        Some(base) => {
-            let path = format!("{}{}", base, url);
-            if path.is_empty() {
-                "/".to_string()
-            } else {
-                path
-            }
-        }
-        None => url,
-    };
-
-    history
-        .push_state_with_url(&JsValue::NULL, "", Some(&path))
-        .expect("push history");
-    let event = Event::new("popstate").unwrap();
-    yew::utils::window()
-        .dispatch_event(&event)
-        .expect("dispatch");
-}
-
 pub fn parse_query<T>() -> Result<T, serde_urlencoded::de::Error>
 where
     T: for<'de> Deserialize<'de>,
 {
-    let query = yew::utils::document().location().unwrap().search().unwrap();
-    serde_urlencoded::from_str(query.strip_prefix('?').unwrap_or(""))
+    navigation::location().query()
 }
 
 pub fn current_route<R: Routable>() -> Option<R> {
@@ -72,7 +51,7 @@ pub fn current_route<R: Routable>() -> Option<R> {
 pub struct RouteListener {
     // this exists so listener is dropped when handle is dropped
     #[allow(dead_code)]
-    listener: EventListener,
+    listener: HistoryListener,
 }
 
 /// Adds a listener which is called when the current route is changed.
@@ -82,9 +61,9 @@ pub fn attach_route_listener<R>(callback: Callback<Option<R>>) -> RouteListener
 where
     R: Routable + 'static,
 {
-    let listener = EventListener::new(&yew::utils::window(), "popstate", move |_| {
-        callback.emit(current_route())
-    });
+    let listener = navigation::listen(Callback::from(move |_| {
+        callback.emit(R::recognize(&navigation::location().to_string()))
+    }));
 
     RouteListener { listener }
 }
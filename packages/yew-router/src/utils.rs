@@ -5,6 +5,22 @@ pub(crate) fn strip_slash_suffix(path: &str) -> &str {
     path.strip_suffix('/').unwrap_or(path)
 }
 
+/// Appends `path` to `base`. The root path `/` is appended as an empty path, unless `base` is
+/// empty as well.
+pub(crate) fn join_path(base: &str, path: &str) -> String {
+    match path.strip_prefix('/') {
+        Some(rest) if !base.is_empty() && (rest.is_empty() || rest.starts_with('?')) => {
+            format!("{}{}", base, rest)
+        }
+        _ => format!("{}{}", base, path),
+    }
+}
+
+/// Returns `path` without its query string.
+pub(crate) fn strip_query(path: &str) -> &str {
+    path.split('?').next().unwrap_or(path)
+}
+
 static BASE_URL_LOADED: std::sync::Once = std::sync::Once::new();
 thread_local! {
     static BASE_URL: RefCell<Option<String>> = RefCell::new(None);
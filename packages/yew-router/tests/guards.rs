@@ -0,0 +1,130 @@
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::functional::function_component;
+use yew::prelude::*;
+use yew_router::history::History;
+use yew_router::prelude::*;
+use yew_router::{GuardResult, MemoryHistory};
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+async fn sleep(millis: i32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        yew::utils::window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, millis)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/login")]
+    Login,
+    #[at("/account")]
+    Account,
+    #[at("/old-account")]
+    OldAccount,
+}
+
+#[function_component(Comp)]
+fn component() -> Html {
+    let switch = Router::render(|routes| match routes {
+        Routes::Home => html! {
+            <>
+                <div id="result">{"Home"}</div>
+                <Link<Routes> route={Routes::Account}>{"account"}</Link<Routes>>
+            </>
+        },
+        Routes::Login => html! { <div id="result">{"Login"}</div> },
+        Routes::Account => html! { <div id="result">{"Account"}</div> },
+        Routes::OldAccount => html! { <Redirect<Routes> to={Routes::Account} /> },
+    });
+
+    html! {
+        <Router<Routes> render={switch} />
+    }
+}
+
+#[test]
+fn guards_and_redirects_work() {
+    let history = MemoryHistory::new();
+    yew_router::set_history(history.clone());
+    yew::start_app_in_element::<Comp>(yew::utils::document().get_element_by_id("output").unwrap());
+
+    let guard = yew_router::add_guard(|navigation| {
+        if navigation.to().path() == "/account" {
+            GuardResult::redirect(Routes::Login)
+        } else {
+            GuardResult::Allow
+        }
+    });
+    click("a");
+    assert_eq!("Login", obtain_result_by_id("result"));
+    assert_eq!(history.location().path(), "/login");
+
+    drop(guard);
+    yew_router::push_route(Routes::OldAccount);
+    assert_eq!("Account", obtain_result_by_id("result"));
+    // the redirect replaced the entry of the old route
+    assert_eq!(history.entries().len(), 3);
+    assert_eq!(history.location().path(), "/account");
+}
+
+#[test]
+async fn async_guards_cancel_and_redirect() {
+    let history = MemoryHistory::new();
+    yew_router::set_history(history.clone());
+    yew::start_app_in_element::<Comp>(yew::utils::document().get_element_by_id("output").unwrap());
+    assert_eq!("Home", obtain_result_by_id("result"));
+
+    let guard = yew_router::add_async_guard(|navigation| {
+        let path = navigation.to().path().to_string();
+        async move {
+            match path.as_str() {
+                "/login" => {
+                    sleep(10).await;
+                    GuardResult::Cancel
+                }
+                "/old-account" => {
+                    sleep(10).await;
+                    GuardResult::redirect(Routes::Account)
+                }
+                "/account" => {
+                    // resolves after the navigation which follows it
+                    sleep(50).await;
+                    GuardResult::Allow
+                }
+                _ => GuardResult::Allow,
+            }
+        }
+    });
+
+    yew_router::push_route(Routes::Login);
+    sleep(30).await;
+    assert_eq!("Home", obtain_result_by_id("result"));
+    assert_eq!(history.location().path(), "/");
+
+    // the previous location is rendered until the guards decide
+    yew_router::push_route(Routes::OldAccount);
+    assert_eq!("Home", obtain_result_by_id("result"));
+    sleep(100).await;
+    assert_eq!("Account", obtain_result_by_id("result"));
+    assert_eq!(history.location().path(), "/account");
+    assert_eq!(history.entries().len(), 2);
+
+    // the guard of the first navigation resolves last, but the second one wins
+    yew_router::push_route(Routes::Account);
+    yew_router::push_route(Routes::Home);
+    sleep(100).await;
+    assert_eq!("Home", obtain_result_by_id("result"));
+    assert_eq!(history.location().path(), "/");
+    assert_eq!(history.entries().len(), 3);
+
+    drop(guard);
+}
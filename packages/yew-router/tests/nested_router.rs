@@ -0,0 +1,87 @@
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::functional::function_component;
+use yew::prelude::*;
+use yew_router::history::History;
+use yew_router::prelude::*;
+use yew_router::MemoryHistory;
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn href(selector: &str) -> String {
+    yew::utils::document()
+        .query_selector(selector)
+        .unwrap()
+        .unwrap()
+        .get_attribute("href")
+        .unwrap()
+}
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/settings/*")]
+    Settings,
+}
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum SettingsRoutes {
+    #[at("/")]
+    Overview,
+    #[at("/profile")]
+    Profile,
+}
+
+fn switch_settings(route: &SettingsRoutes) -> Html {
+    match route {
+        SettingsRoutes::Overview => html! {
+            <>
+                <div id="result">{"Overview"}</div>
+                <Link<SettingsRoutes> route={SettingsRoutes::Profile}>{"profile"}</Link<SettingsRoutes>>
+            </>
+        },
+        SettingsRoutes::Profile => html! { <div id="result">{"Profile"}</div> },
+    }
+}
+
+#[function_component(Comp)]
+fn component() -> Html {
+    let switch = Router::render(|routes| match routes {
+        Routes::Home => html! {
+            <>
+                <div id="result">{"Home"}</div>
+                <Link<Routes> route={Routes::Settings}>{"settings"}</Link<Routes>>
+            </>
+        },
+        Routes::Settings => html! {
+            <Router<SettingsRoutes> render={Router::render(switch_settings)} />
+        },
+    });
+
+    html! {
+        <Router<Routes> render={switch} />
+    }
+}
+
+#[test]
+fn nested_router_matches_remainder() {
+    let history = MemoryHistory::new();
+    yew_router::set_history(history.clone());
+    yew::start_app_in_element::<Comp>(yew::utils::document().get_element_by_id("output").unwrap());
+
+    assert_eq!("Home", obtain_result_by_id("result"));
+    assert_eq!(href("a"), "/settings");
+
+    click("a");
+    assert_eq!("Overview", obtain_result_by_id("result"));
+    assert_eq!(history.location().path(), "/settings");
+    // the link is relative to the route of the parent router
+    assert_eq!(href("a"), "/settings/profile");
+
+    click("a");
+    assert_eq!("Profile", obtain_result_by_id("result"));
+    assert_eq!(history.location().path(), "/settings/profile");
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+use yew::functional::function_component;
+use yew::prelude::*;
+use yew_router::history::History;
+use yew_router::prelude::*;
+use yew_router::{use_query, MemoryHistory};
+
+mod utils;
+use utils::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SearchQuery {
+    q: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PageQuery {
+    page: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Routable)]
+enum Routes {
+    #[at("/")]
+    Home,
+    #[at("/search")]
+    Search(#[query] SearchQuery),
+}
+
+#[function_component(Page)]
+fn page() -> Html {
+    let page = use_query::<PageQuery>().unwrap().page.unwrap_or(1);
+
+    html! { <div id="result">{ format!("page {}", page) }</div> }
+}
+
+#[function_component(Comp)]
+fn component() -> Html {
+    let switch = Router::render(|routes| match routes {
+        Routes::Home => html! {
+            <>
+                <Page />
+                <Link<Routes, PageQuery> classes={classes!("next")} route={Routes::Home} query={Some(PageQuery { page: Some(2) })}>
+                    {"next"}
+                </Link<Routes, PageQuery>>
+                <Link<Routes> classes={classes!("search")} route={Routes::Search(SearchQuery { q: "yew".to_string() })}>
+                    {"search"}
+                </Link<Routes>>
+            </>
+        },
+        Routes::Search(query) => html! { <div id="result">{ query.q.clone() }</div> },
+    });
+
+    html! {
+        <Router<Routes> render={switch} />
+    }
+}
+
+#[test]
+fn queries_are_part_of_routes_and_links() {
+    let history = MemoryHistory::new();
+    yew_router::set_history(history.clone());
+    yew::start_app_in_element::<Comp>(yew::utils::document().get_element_by_id("output").unwrap());
+
+    assert_eq!("page 1", obtain_result_by_id("result"));
+
+    click("a.next");
+    assert_eq!(history.location().to_string(), "/?page=2");
+    // only the query changed, which re-renders the component using it
+    assert_eq!("page 2", obtain_result_by_id("result"));
+
+    click("a.search");
+    assert_eq!(history.location().to_string(), "/search?q=yew");
+    assert_eq!("yew", obtain_result_by_id("result"));
+}
@@ -4,7 +4,9 @@
 use std::ops::Deref;
 
 use crate::html::{Component, NodeRef, Scope, Scoped};
+use crate::scheduler;
 use crate::utils::document;
+use crate::virtual_dom::{register_root, remove_unhydrated, unregister_root};
 use web_sys::Element;
 
 /// An instance of an application.
@@ -12,6 +14,8 @@ use web_sys::Element;
 pub struct AppHandle<COMP: Component> {
     /// `Scope` holder
     pub(crate) scope: Scope<COMP>,
+    /// The element the application is mounted in
+    root: Element,
 }
 
 impl<COMP> AppHandle<COMP>
@@ -24,8 +28,10 @@ where
     /// will render the model to a virtual DOM tree.
     pub(crate) fn mount_with_props(element: Element, props: COMP::Properties) -> Self {
         clear_element(&element);
+        register_root(&element);
         let app = Self {
             scope: Scope::new(None),
+            root: element.clone(),
         };
         app.scope
             .mount_in_place(element, NodeRef::default(), NodeRef::default(), props);
@@ -33,6 +39,31 @@ where
         app
     }
 
+    /// Alternative to `mount_with_props` which adopts the existing children of `element`,
+    /// usually rendered on the server by [`ServerRenderer`](crate::ServerRenderer), instead of
+    /// clearing them. Listeners are attached to the adopted nodes and nodes which do not match the
+    /// view are replaced.
+    pub(crate) fn hydrate_with_props(element: Element, props: COMP::Properties) -> Self {
+        register_root(&element);
+        let app = Self {
+            scope: Scope::new(None),
+            root: element.clone(),
+        };
+        scheduler::locked(|| {
+            let mut fragment = element.first_child();
+            app.scope.hydrate_in_place(
+                element.clone(),
+                &mut fragment,
+                NodeRef::default(),
+                NodeRef::default(),
+                props,
+            );
+            remove_unhydrated(&element, fragment);
+        });
+
+        app
+    }
+
     /// Alternative to `mount_with_props` which replaces the body element with a component which
     /// has a body element at the root of the HTML generated by its `view` method. Use this method
     /// when you need to manipulate the body element. For example, adding/removing app-wide
@@ -49,7 +80,8 @@ where
 
     /// Schedule the app for destruction
     pub fn destroy(mut self) {
-        self.scope.destroy()
+        self.scope.destroy();
+        unregister_root(&self.root);
     }
 }
 
@@ -1,9 +1,10 @@
 //! This module defines the `ContextProvider` component.
 
-use crate::html::Scope;
+use crate::html::{AnyScope, Scope};
 use crate::{html, Callback, Children, Component, ComponentLink, Html, Properties};
 use slab::Slab;
 use std::cell::RefCell;
+use std::rc::Rc;
 
 /// Props for [`ContextProvider`]
 #[derive(Debug, Clone, PartialEq, Properties)]
@@ -17,8 +18,12 @@ pub struct ContextProviderProps<T: Clone + PartialEq> {
 /// The context provider component.
 ///
 /// Every child (direct or indirect) of this component may access the context value.
-/// In order to consume contexts, [`ComponentLink::context`][Scope::context] method is used,
-/// In function components the `use_context` hook is used.
+/// In order to consume contexts, [`ComponentLink::consume_context`][Scope::consume_context] method
+/// is used, In function components the `use_context` hook is used.
+///
+/// Consumers can subscribe to a part of the context selected by a function, for example with
+/// [`ComponentLink::consume_context_selector`][Scope::consume_context_selector], so they are only
+/// notified when the selected part changes.
 #[derive(Debug)]
 pub struct ContextProvider<T: Clone + PartialEq + 'static> {
     link: ComponentLink<Self>,
@@ -43,6 +48,79 @@ impl<T: Clone + PartialEq + 'static> Drop for ContextHandle<T> {
     }
 }
 
+impl<T: Clone + PartialEq + 'static> ContextHandle<T> {
+    /// Calls `f` with the current value of the context, if the provider is still alive.
+    pub(crate) fn with_context<R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        let component = self.provider.get_component()?;
+        Some(f(&component.context))
+    }
+}
+
+/// The selector of a [`SelectorSubscription`], which can be replaced while it is subscribed.
+type Selector<T, S> = Rc<RefCell<Box<dyn Fn(&T) -> S>>>;
+
+/// A subscription to the part of a context selected by a selector which can be replaced, so the
+/// selector may capture values which change between renders.
+pub(crate) struct SelectorSubscription<T: Clone + PartialEq + 'static, S> {
+    selector: Selector<T, S>,
+    /// The value selected by the latest selector, which changes are detected against
+    last_selected: Rc<RefCell<S>>,
+    handle: ContextHandle<T>,
+}
+
+impl<T, S> SelectorSubscription<T, S>
+where
+    T: Clone + PartialEq + 'static,
+    S: Clone + PartialEq + 'static,
+{
+    /// Subscribes to the part selected by `selector` of the context provided above `scope` and
+    /// returns the selected value. `callback` is called when the selected value changes.
+    pub(crate) fn subscribe(
+        scope: &AnyScope,
+        selector: impl Fn(&T) -> S + 'static,
+        callback: Callback<S>,
+    ) -> Option<(S, Self)> {
+        let provider = scope.find_parent_scope::<ContextProvider<T>>()?;
+        let component = provider.get_component()?;
+
+        let selector: Selector<T, S> = Rc::new(RefCell::new(Box::new(selector)));
+        let selected = (selector.borrow())(&component.context);
+        let last_selected = Rc::new(RefCell::new(selected.clone()));
+        let consumer = {
+            let selector = Rc::clone(&selector);
+            let last_selected = Rc::clone(&last_selected);
+            Callback::from(move |ctx: T| {
+                let selected = (selector.borrow())(&ctx);
+                if *last_selected.borrow() != selected {
+                    *last_selected.borrow_mut() = selected.clone();
+                    callback.emit(selected);
+                }
+            })
+        };
+        let (_, handle) = component.subscribe_consumer(consumer);
+
+        Some((
+            selected,
+            Self {
+                selector,
+                last_selected,
+                handle,
+            },
+        ))
+    }
+
+    /// Replaces the selector and returns the part it selects of the current context, or `None`
+    /// if the provider has been destroyed.
+    pub(crate) fn select(&self, selector: impl Fn(&T) -> S + 'static) -> Option<S> {
+        *self.selector.borrow_mut() = Box::new(selector);
+        let selected = self
+            .handle
+            .with_context(|context| (self.selector.borrow())(context))?;
+        *self.last_selected.borrow_mut() = selected.clone();
+        Some(selected)
+    }
+}
+
 impl<T: Clone + PartialEq> ContextProvider<T> {
     /// Add the callback to the subscriber list to be called whenever the context changes.
     /// The consumer is unsubscribed as soon as the callback is dropped.
@@ -59,6 +137,31 @@ impl<T: Clone + PartialEq> ContextProvider<T> {
         )
     }
 
+    /// Add a subscriber which is only called when the part of the context selected by `selector`
+    /// changes. The consumer is unsubscribed as soon as the returned handle is dropped.
+    pub(crate) fn subscribe_consumer_with_selector<S, F>(
+        &self,
+        selector: F,
+        callback: Callback<S>,
+    ) -> (S, ContextHandle<T>)
+    where
+        S: Clone + PartialEq + 'static,
+        F: Fn(&T) -> S + 'static,
+    {
+        let selected = selector(&self.context);
+        let last_selected = RefCell::new(selected.clone());
+        let consumer = Callback::from(move |ctx: T| {
+            let selected = selector(&ctx);
+            if *last_selected.borrow() != selected {
+                *last_selected.borrow_mut() = selected.clone();
+                callback.emit(selected);
+            }
+        });
+        let (_, handle) = self.subscribe_consumer(consumer);
+
+        (selected, handle)
+    }
+
     /// Notify all subscribed consumers and remove dropped consumers from the list.
     fn notify_consumers(&mut self) {
         let consumers: Vec<Callback<T>> = self
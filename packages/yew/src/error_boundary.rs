@@ -0,0 +1,253 @@
+//! This module defines the `ErrorBoundary` component.
+
+use crate::html::{AnyScope, Scope};
+use crate::{html, Children, Component, ComponentLink, Html, Properties};
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+/// Wraps `Rc` around `Fn` so it can be passed as a prop.
+pub struct FallbackFn(Rc<dyn Fn(&str) -> Html>);
+
+impl FallbackFn {
+    /// Creates a new [`FallbackFn`]
+    ///
+    /// It is recommended that you use [`ErrorBoundary::fallback`] instead
+    pub fn new(value: impl Fn(&str) -> Html + 'static) -> Self {
+        Self(Rc::new(value))
+    }
+
+    fn render(&self, error: &str) -> Html {
+        (self.0)(error)
+    }
+}
+
+impl Clone for FallbackFn {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl PartialEq for FallbackFn {
+    fn eq(&self, other: &Self) -> bool {
+        // https://github.com/rust-lang/rust-clippy/issues/6524
+        #[allow(clippy::vtable_address_comparisons)]
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for FallbackFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FallbackFn<_>")
+    }
+}
+
+/// Props for [`ErrorBoundary`]
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ErrorBoundaryProps {
+    /// Children
+    #[prop_or_default]
+    pub children: Children,
+    /// Renders the [`Html`] shown instead of the children from the message of the caught panic
+    pub fallback: FallbackFn,
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub enum ErrorBoundaryMsg {
+    Caught(String),
+}
+
+/// The error boundary component.
+///
+/// When a child (direct or indirect) of this component panics while it is created, updated or
+/// rendered, the children are destroyed and the `fallback` is rendered with the panic message
+/// instead. Panics which are caught by an error boundary don't stop the scheduler, so the rest of
+/// the application keeps working. The `destroy` method of the component which panicked isn't
+/// called.
+///
+/// Panics can only be caught if they unwind. On `wasm32-unknown-unknown`, where panics abort by
+/// default (`panic=abort`), an error boundary doesn't catch anything: the panic stops the whole
+/// application, as it does without an error boundary.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// # #[function_component(Widget)]
+/// # fn widget() -> Html {
+/// #     html! {}
+/// # }
+/// #
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let fallback = ErrorBoundary::fallback(|error| html! {
+///         <p>{ format!("Something went wrong: {}", error) }</p>
+///     });
+///
+///     html! {
+///         <ErrorBoundary {fallback}>
+///             <Widget />
+///         </ErrorBoundary>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ErrorBoundary {
+    props: ErrorBoundaryProps,
+    error: Option<String>,
+}
+
+impl ErrorBoundary {
+    /// Creates a [`FallbackFn`] which renders the fallback from the message of the caught panic.
+    pub fn fallback(f: impl Fn(&str) -> Html + 'static) -> FallbackFn {
+        FallbackFn::new(f)
+    }
+}
+
+impl Component for ErrorBoundary {
+    type Message = ErrorBoundaryMsg;
+    type Properties = ErrorBoundaryProps;
+
+    fn create(props: Self::Properties, _link: ComponentLink<Self>) -> Self {
+        Self { props, error: None }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> bool {
+        match msg {
+            // Only the first panic is shown, the ones following it are caused by the same subtree
+            ErrorBoundaryMsg::Caught(error) if self.error.is_none() => {
+                self.error = Some(error);
+                true
+            }
+            ErrorBoundaryMsg::Caught(_) => false,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        match &self.error {
+            Some(error) => self.props.fallback.render(error),
+            None => html! { <>{ self.props.children.clone() }</> },
+        }
+    }
+}
+
+/// Processes a lifecycle event of the component at `scope` by calling `f`. If it panics,
+/// `tear_down` is called once the panic unwound and the panic is passed to the nearest
+/// [`ErrorBoundary`] above the component. Unwinding is resumed if there is none.
+pub(crate) fn run(scope: &AnyScope, f: impl FnOnce(), tear_down: impl FnOnce()) {
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(f)) {
+        match find_boundary(scope) {
+            Some(boundary) => {
+                tear_down();
+                boundary.send_message(ErrorBoundaryMsg::Caught(panic_message(&*payload)));
+            }
+            None => panic::resume_unwind(payload),
+        }
+    }
+}
+
+fn find_boundary(scope: &AnyScope) -> Option<Scope<ErrorBoundary>> {
+    scope
+        .get_parent()
+        .and_then(|parent| parent.find_parent_scope::<ErrorBoundary>())
+}
+
+/// Writes the HTML of the fallback to `w` if the component at `scope` is the [`ErrorBoundary`]
+/// which catches the panic of one of its children while they were rendered to a string. Resumes
+/// unwinding otherwise.
+pub(crate) fn render_fallback(scope: &AnyScope, w: &mut String, payload: Box<dyn Any + Send>) {
+    let boundary = scope
+        .find_parent_scope::<ErrorBoundary>()
+        .filter(|boundary| scope.ptr_eq(&boundary.clone().into()));
+    let fallback = boundary.as_ref().and_then(|boundary| {
+        let component = boundary.get_component()?;
+        Some(component.props.fallback.render(&panic_message(&*payload)))
+    });
+    match fallback {
+        Some(fallback) => fallback.render_to_string(w, scope),
+        None => panic::resume_unwind(payload),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate self as yew;
+
+    use crate::prelude::*;
+    use crate::ServerRenderer;
+
+    #[function_component(Panicking)]
+    fn panicking() -> Html {
+        panic!("oh no")
+    }
+
+    #[function_component(App)]
+    fn app() -> Html {
+        let fallback = ErrorBoundary::fallback(|error| html! { <p>{ error }</p> });
+
+        html! {
+            <div>
+                <ErrorBoundary {fallback}>
+                    <span>{ "Hidden" }</span>
+                    <Panicking />
+                </ErrorBoundary>
+                <span>{ "Shown" }</span>
+            </div>
+        }
+    }
+
+    #[test]
+    fn renders_fallback_when_children_panic() {
+        let html = ServerRenderer::<App>::new().render();
+
+        assert_eq!(html, "<div><p>oh no</p><span>Shown</span></div>");
+    }
+
+    #[function_component(Wrapper)]
+    fn wrapper() -> Html {
+        html! { <section><Panicking /></section> }
+    }
+
+    #[function_component(Nested)]
+    fn nested() -> Html {
+        let outer = ErrorBoundary::fallback(|_| html! { <p>{ "outer" }</p> });
+        let inner = ErrorBoundary::fallback(|_| html! { <p>{ "inner" }</p> });
+
+        html! {
+            <ErrorBoundary fallback={outer}>
+                <span>{ "Shown" }</span>
+                <ErrorBoundary fallback={inner}>
+                    <Wrapper />
+                </ErrorBoundary>
+            </ErrorBoundary>
+        }
+    }
+
+    #[test]
+    fn renders_fallback_of_nearest_boundary() {
+        let html = ServerRenderer::<Nested>::new().render();
+
+        assert_eq!(html, "<span>Shown</span><p>inner</p>");
+    }
+}
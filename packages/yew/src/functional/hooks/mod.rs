@@ -1,14 +1,32 @@
+mod use_callback;
 mod use_context;
 mod use_effect;
+mod use_force_update;
+mod use_future;
+mod use_layout_effect;
+mod use_memo;
+mod use_mount;
+mod use_node_ref;
+mod use_previous;
 mod use_reducer;
 mod use_ref;
 mod use_state;
+mod use_unmount;
 
+pub use use_callback::*;
 pub use use_context::*;
 pub use use_effect::*;
+pub use use_force_update::*;
+pub use use_future::*;
+pub use use_layout_effect::*;
+pub use use_memo::*;
+pub use use_mount::*;
+pub use use_node_ref::*;
+pub use use_previous::*;
 pub use use_reducer::*;
 pub use use_ref::*;
 pub use use_state::*;
+pub use use_unmount::*;
 
 use crate::functional::{HookUpdater, CURRENT_HOOK};
 use std::cell::RefCell;
@@ -57,6 +75,7 @@ pub fn use_hook<InternalHook: 'static, Output, Tear: FnOnce(&mut InternalHook) +
         HookUpdater {
             hook,
             process_message: hook_state.process_message.clone(),
+            rendered_queue: hook_state.rendered_queue.clone(),
         }
     });
 
@@ -0,0 +1,61 @@
+use crate::callback::Callback;
+use crate::functional::use_memo;
+use std::rc::Rc;
+
+/// This hook is used for obtaining a [`Callback`] which is only created again when the
+/// dependencies change.
+///
+/// Passing a callback which is the same across renders to a child lets the child skip rendering
+/// when its other props did not change. To detect changes, dependencies must implement
+/// `PartialEq`.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[derive(Clone, PartialEq, Properties)]
+/// pub struct ButtonProps {
+///     pub onclick: Callback<MouseEvent>,
+/// }
+///
+/// #[function_component(Button)]
+/// fn button(props: &ButtonProps) -> Html {
+///     html! {
+///         <button onclick={props.onclick.clone()}>{ "Click" }</button>
+///     }
+/// }
+///
+/// #[derive(Clone, PartialEq, Properties)]
+/// pub struct ToolbarProps {
+///     pub name: String,
+/// }
+///
+/// #[function_component(Toolbar)]
+/// fn toolbar(props: &ToolbarProps) -> Html {
+///     // The callback is the same across renders until `name` changes, so `Button` is not
+///     // rendered again in the meantime
+///     let onclick = use_callback(
+///         |_: MouseEvent, name| log::info!("{} clicked", name),
+///         props.name.clone(),
+///     );
+///
+///     html! {
+///         <Button {onclick} />
+///     }
+/// }
+/// ```
+pub fn use_callback<IN, F, D>(f: F, deps: D) -> Callback<IN>
+where
+    IN: 'static,
+    F: Fn(IN, &D) + 'static,
+    D: PartialEq + 'static,
+{
+    (*use_memo(
+        move |deps| {
+            let deps = deps.clone();
+            Callback::from(move |value| f(value, deps.as_ref()))
+        },
+        Rc::new(deps),
+    ))
+    .clone()
+}
@@ -1,4 +1,4 @@
-use crate::context::ContextHandle;
+use crate::context::{ContextHandle, SelectorSubscription};
 use crate::functional::{get_current_scope, use_hook};
 
 /// Hook for consuming context values in function components.
@@ -63,3 +63,86 @@ pub fn use_context<T: Clone + PartialEq + 'static>() -> Option<T> {
         },
     )
 }
+
+/// Hook for consuming a part of a context value in function components.
+/// The part of the context of type `T` selected by `selector` is returned. If there is no such
+/// context in scope, `None` is returned.
+///
+/// Unlike [`use_context`], the component is only re-rendered when the selected value changes, not
+/// every time the data of the context changes. This is useful for large contexts like the state
+/// of an application, of which most components only need a small part.
+///
+/// The selector passed on the latest render is used to select values, so it may capture props or
+/// state. The type of the context can also be given explicitly, as in
+/// `use_context_selector::<Rc<AppState>, _, _>(|state| ...)`.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use std::rc::Rc;
+///
+/// # #[derive(Clone, Debug, PartialEq)]
+/// # struct User;
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct AppState {
+///     user_name: String,
+///     users: Vec<User>,
+/// }
+///
+/// #[function_component(Greeting)]
+/// pub fn greeting() -> Html {
+///     // Not re-rendered when the list of users changes
+///     let user_name = use_context_selector(|state: &Rc<AppState>| state.user_name.clone())
+///         .expect("no ctx found");
+///
+///     html! {
+///         <p>{ format!("Hello, {}!", user_name) }</p>
+///     }
+/// }
+/// ```
+pub fn use_context_selector<T, U, F>(selector: F) -> Option<U>
+where
+    T: Clone + PartialEq + 'static,
+    U: Clone + PartialEq + 'static,
+    F: Fn(&T) -> U + 'static,
+{
+    struct UseContextSelectorState<T2: Clone + PartialEq + 'static, U2> {
+        initialized: bool,
+        context: Option<(U2, SelectorSubscription<T2, U2>)>,
+    }
+
+    let scope = get_current_scope().expect(
+        "No current Scope. `use_context_selector` can only be called inside function components",
+    );
+
+    use_hook(
+        move || UseContextSelectorState {
+            initialized: false,
+            context: None,
+        },
+        |state: &mut UseContextSelectorState<T, U>, updater| {
+            if !state.initialized {
+                state.initialized = true;
+                let callback = move |selected: U| {
+                    updater.callback(|state: &mut UseContextSelectorState<T, U>| {
+                        if let Some(context) = &mut state.context {
+                            context.0 = selected;
+                        }
+                        true
+                    });
+                };
+                state.context = SelectorSubscription::subscribe(&scope, selector, callback.into());
+            } else if let Some((value, subscription)) = &mut state.context {
+                // The selector may capture values of this render, so it replaces the previous one
+                if let Some(selected) = subscription.select(selector) {
+                    *value = selected;
+                }
+            }
+
+            Some(state.context.as_ref()?.0.clone())
+        },
+        |state| {
+            state.context = None;
+        },
+    )
+}
@@ -1,6 +1,28 @@
-use crate::functional::use_hook;
+use crate::functional::{use_hook, HookUpdater};
 use std::{borrow::Borrow, rc::Rc};
 
+/// The phase in which the callback of an effect runs.
+#[derive(Clone, Copy)]
+pub(super) enum EffectPhase {
+    /// Synchronously after the DOM is updated, see [`HookUpdater::rendered`]
+    Rendered,
+    /// After the render is complete, see [`HookUpdater::post_render`]
+    PostRender,
+}
+
+impl EffectPhase {
+    fn schedule<T: 'static>(
+        self,
+        updater: &HookUpdater,
+        cb: impl FnOnce(&mut T) -> bool + 'static,
+    ) {
+        match self {
+            Self::Rendered => updater.rendered(cb),
+            Self::PostRender => updater.post_render(cb),
+        }
+    }
+}
+
 struct UseEffect<Destructor> {
     destructor: Option<Box<Destructor>>,
 }
@@ -38,6 +60,15 @@ struct UseEffect<Destructor> {
 pub fn use_effect<Destructor>(callback: impl FnOnce() -> Destructor + 'static)
 where
     Destructor: FnOnce() + 'static,
+{
+    use_effect_in(EffectPhase::PostRender, callback)
+}
+
+pub(super) fn use_effect_in<Destructor>(
+    phase: EffectPhase,
+    callback: impl FnOnce() -> Destructor + 'static,
+) where
+    Destructor: FnOnce() + 'static,
 {
     let callback = Box::new(callback);
     use_hook(
@@ -47,7 +78,7 @@ where
         },
         |_, updater| {
             // Run on every render
-            updater.post_render(move |state: &mut UseEffect<Destructor>| {
+            phase.schedule(&updater, move |state: &mut UseEffect<Destructor>| {
                 if let Some(de) = state.destructor.take() {
                     de();
                 }
@@ -79,6 +110,18 @@ where
     Callback: FnOnce(&Dependents) -> Destructor + 'static,
     Destructor: FnOnce() + 'static,
     Dependents: PartialEq + 'static,
+{
+    use_effect_with_deps_in(EffectPhase::PostRender, callback, deps)
+}
+
+pub(super) fn use_effect_with_deps_in<Callback, Destructor, Dependents>(
+    phase: EffectPhase,
+    callback: Callback,
+    deps: Dependents,
+) where
+    Callback: FnOnce(&Dependents) -> Destructor + 'static,
+    Destructor: FnOnce() + 'static,
+    Dependents: PartialEq + 'static,
 {
     let deps = Rc::new(deps);
     let deps_c = deps.clone();
@@ -92,21 +135,24 @@ where
             }
         },
         move |_, updater| {
-            updater.post_render(move |state: &mut UseEffectDeps<Destructor, Dependents>| {
-                if state.deps != deps {
-                    if let Some(de) = state.destructor.take() {
-                        de();
+            phase.schedule(
+                &updater,
+                move |state: &mut UseEffectDeps<Destructor, Dependents>| {
+                    if state.deps != deps {
+                        if let Some(de) = state.destructor.take() {
+                            de();
+                        }
+                        let new_destructor = callback(deps.borrow());
+                        state.deps = deps;
+                        state.destructor.replace(Box::new(new_destructor));
+                    } else if state.destructor.is_none() {
+                        state
+                            .destructor
+                            .replace(Box::new(callback(state.deps.borrow())));
                     }
-                    let new_destructor = callback(deps.borrow());
-                    state.deps = deps;
-                    state.destructor.replace(Box::new(new_destructor));
-                } else if state.destructor.is_none() {
-                    state
-                        .destructor
-                        .replace(Box::new(callback(state.deps.borrow())));
-                }
-                false
-            });
+                    false
+                },
+            );
         },
         |hook| {
             if let Some(destructor) = hook.destructor.take() {
@@ -0,0 +1,72 @@
+use crate::functional::use_hook;
+use std::fmt;
+use std::rc::Rc;
+
+struct UseForceUpdate {
+    trigger: Option<Rc<dyn Fn()>>,
+}
+
+/// This hook is used for re-rendering the component on demand, for example when it reads state
+/// which isn't managed by hooks.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[function_component(UseForceUpdate)]
+/// fn force_update() -> Html {
+///     let trigger = use_force_update();
+///
+///     let onclick = Callback::from(move |_| trigger.force_update());
+///
+///     html! {
+///         <div>
+///             <p>{ js_sys::Date::now() }</p>
+///             <button {onclick}>{ "Refresh" }</button>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_force_update() -> UseForceUpdateHandle {
+    use_hook(
+        || UseForceUpdate { trigger: None },
+        |hook, updater| {
+            let trigger = hook.trigger.get_or_insert_with(|| {
+                Rc::new(move || updater.callback(|_: &mut UseForceUpdate| true))
+            });
+
+            UseForceUpdateHandle {
+                trigger: Rc::clone(trigger),
+            }
+        },
+        // The trigger holds the state of the hook, drop it to not leak the state
+        |hook| hook.trigger = None,
+    )
+}
+
+/// Handle for the [`use_force_update`] hook.
+///
+/// It stays the same for the lifetime of the component, so it compares equal across renders.
+#[derive(Clone)]
+pub struct UseForceUpdateHandle {
+    trigger: Rc<dyn Fn()>,
+}
+
+impl UseForceUpdateHandle {
+    /// Re-renders the component
+    pub fn force_update(&self) {
+        (self.trigger)()
+    }
+}
+
+impl PartialEq for UseForceUpdateHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.trigger, &other.trigger)
+    }
+}
+
+impl fmt::Debug for UseForceUpdateHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseForceUpdateHandle").finish()
+    }
+}
@@ -0,0 +1,192 @@
+use crate::functional::use_hook;
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use wasm_bindgen_futures::spawn_local;
+
+/// The state of a future run by [`use_future_with_deps`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum UseFutureState<T, E> {
+    /// The future hasn't resolved yet
+    Pending,
+    /// The future resolved to `Ok`
+    Ready(T),
+    /// The future resolved to `Err`
+    Error(E),
+}
+
+impl<T, E> From<Result<T, E>> for UseFutureState<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(value) => Self::Ready(value),
+            Err(error) => Self::Error(error),
+        }
+    }
+}
+
+struct UseFutureWithDeps<T, E, D> {
+    state: Rc<UseFutureState<T, E>>,
+    deps: Option<D>,
+    /// Aborts the future which is still running, if any
+    abort: Option<AbortHandle>,
+    /// Incremented for every future, so the output of a previous future is never stored
+    generation: u32,
+}
+
+/// This hook is used to run a future in a function component and render its output.
+///
+/// The future is created by `f` on the first render and every time the dependencies change,
+/// and spawned on the current thread once the component has been rendered. It is never run when
+/// the component is rendered on the server. The hook returns the state of the latest future:
+/// [`UseFutureState::Pending`] until it resolves, and then [`UseFutureState::Ready`] or
+/// [`UseFutureState::Error`]. The component is re-rendered when the future resolves.
+///
+/// A future which is still running when the dependencies change or the component is destroyed is
+/// aborted: it is dropped the next time it would be polled, and its output is never used. To
+/// detect changes, dependencies must implement `PartialEq`.
+///
+/// Unlike [`suspense::use_future`](crate::suspense::use_future), this hook doesn't suspend the
+/// component, so it can render the pending state itself.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// # async fn fetch_user(id: u32) -> Result<String, String> {
+/// #     Ok(format!("User {}", id))
+/// # }
+/// #
+/// #[derive(Clone, PartialEq, Properties)]
+/// pub struct Props {
+///     pub id: u32,
+/// }
+///
+/// #[function_component(User)]
+/// fn user(props: &Props) -> Html {
+///     let user = use_future_with_deps(|id| fetch_user(*id), props.id);
+///
+///     match &*user {
+///         UseFutureState::Pending => html! { <p>{ "Loading..." }</p> },
+///         UseFutureState::Ready(name) => html! { <p>{ format!("Hello, {}!", name) }</p> },
+///         UseFutureState::Error(error) => html! { <p>{ format!("Error: {}", error) }</p> },
+///     }
+/// }
+/// ```
+pub fn use_future_with_deps<T, E, F, Fut, D>(f: F, deps: D) -> Rc<UseFutureState<T, E>>
+where
+    T: 'static,
+    E: 'static,
+    F: FnOnce(&D) -> Fut,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    D: PartialEq + 'static,
+{
+    use_hook(
+        || UseFutureWithDeps {
+            state: Rc::new(UseFutureState::Pending),
+            deps: None,
+            abort: None,
+            generation: 0,
+        },
+        move |hook, updater| {
+            if hook.deps.as_ref() != Some(&deps) {
+                if let Some(abort) = hook.abort.take() {
+                    abort.abort();
+                }
+
+                let (future, abort) = Abortable::new(f(&deps));
+                hook.generation = hook.generation.wrapping_add(1);
+                hook.state = Rc::new(UseFutureState::Pending);
+                hook.deps = Some(deps);
+                hook.abort = Some(abort);
+
+                let generation = hook.generation;
+                let task_updater = updater.clone();
+                let task = async move {
+                    if let Some(output) = future.await {
+                        task_updater.callback(move |hook: &mut UseFutureWithDeps<T, E, D>| {
+                            if hook.generation != generation {
+                                return false;
+                            }
+                            hook.state = Rc::new(output.into());
+                            hook.abort = None;
+                            true
+                        });
+                    }
+                };
+                // Spawning from the render would run the future for renders which are never
+                // committed, like the ones on the server
+                updater.post_render(move |_: &mut UseFutureWithDeps<T, E, D>| {
+                    spawn_local(task);
+                    false
+                });
+            }
+
+            hook.state.clone()
+        },
+        |hook| {
+            if let Some(abort) = hook.abort.take() {
+                abort.abort();
+            }
+        },
+    )
+}
+
+#[derive(Default)]
+struct AbortState {
+    aborted: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+}
+
+/// Aborts the [`Abortable`] future it was created with.
+struct AbortHandle {
+    state: Rc<AbortState>,
+}
+
+impl AbortHandle {
+    fn abort(&self) {
+        self.state.aborted.set(true);
+        if let Some(waker) = self.state.waker.borrow_mut().take() {
+            waker.wake();
+        }
+    }
+}
+
+/// A future which resolves to `None` without polling the inner future again once it is aborted.
+struct Abortable<F> {
+    future: Pin<Box<F>>,
+    state: Rc<AbortState>,
+}
+
+impl<F: Future> Abortable<F> {
+    fn new(future: F) -> (Self, AbortHandle) {
+        let state = Rc::new(AbortState::default());
+        let handle = AbortHandle {
+            state: state.clone(),
+        };
+        let future = Self {
+            future: Box::pin(future),
+            state,
+        };
+        (future, handle)
+    }
+}
+
+impl<F: Future> Future for Abortable<F> {
+    type Output = Option<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.state.aborted.get() {
+            return Poll::Ready(None);
+        }
+
+        match self.future.as_mut().poll(cx) {
+            Poll::Ready(output) => Poll::Ready(Some(output)),
+            Poll::Pending => {
+                *self.state.waker.borrow_mut() = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use super::use_effect::{use_effect_in, use_effect_with_deps_in, EffectPhase};
+
+/// This hook is similar to [`use_effect`](super::use_effect()) but its callback runs
+/// synchronously after the DOM of the component is updated.
+///
+/// The callbacks of a render run in the following order:
+/// 1. The DOM of the component and of its children is created or updated.
+/// 2. The layout effects of the children run, followed by the layout effects of the component in
+///    the order in which they were called. This happens in the
+///    [`rendered`](crate::Component::rendered) phase of the component, before any other component
+///    is updated.
+/// 3. The effects of the component run after all pending components have been rendered.
+///
+/// Use it to measure the DOM and write the result back before the browser paints, e.g. to
+/// position a tooltip. Prefer [`use_effect`](super::use_effect()) otherwise, as the rendering of
+/// other components waits for layout effects.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use web_sys::HtmlElement;
+/// #
+/// #[function_component(Tooltip)]
+/// fn tooltip() -> Html {
+///     let node_ref = use_ref(NodeRef::default).borrow().clone();
+///
+///     {
+///         let node_ref = node_ref.clone();
+///         use_layout_effect(move || {
+///             if let Some(el) = node_ref.cast::<HtmlElement>() {
+///                 let top = el.offset_height() + 4;
+///                 el.set_attribute("style", &format!("top: {}px", top)).unwrap();
+///             }
+///             || ()
+///         });
+///     }
+///
+///     html! {
+///         <div ref={node_ref}>{ "Tooltip" }</div>
+///     }
+/// }
+/// ```
+pub fn use_layout_effect<Destructor>(callback: impl FnOnce() -> Destructor + 'static)
+where
+    Destructor: FnOnce() + 'static,
+{
+    use_effect_in(EffectPhase::Rendered, callback)
+}
+
+/// This hook is similar to [`use_layout_effect`] but it accepts dependencies.
+///
+/// Whenever the dependencies are changed, the effect callback is called again.
+/// To detect changes, dependencies must implement `PartialEq`.
+/// Note that the destructor also runs when dependencies change.
+pub fn use_layout_effect_with_deps<Callback, Destructor, Dependents>(
+    callback: Callback,
+    deps: Dependents,
+) where
+    Callback: FnOnce(&Dependents) -> Destructor + 'static,
+    Destructor: FnOnce() + 'static,
+    Dependents: PartialEq + 'static,
+{
+    use_effect_with_deps_in(EffectPhase::Rendered, callback, deps)
+}
@@ -0,0 +1,58 @@
+use crate::functional::use_hook;
+use std::rc::Rc;
+
+/// This hook is used for obtaining an immutable reference to a value which is derived from
+/// dependencies.
+///
+/// The value is only computed again when the dependencies change. To detect changes, dependencies
+/// must implement `PartialEq`.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[derive(Clone, PartialEq, Properties)]
+/// pub struct Props {
+///     pub items: Vec<String>,
+///     pub filter: String,
+/// }
+///
+/// #[function_component(FilteredList)]
+/// fn filtered_list(props: &Props) -> Html {
+///     let filtered = use_memo(
+///         |(items, filter)| {
+///             items
+///                 .iter()
+///                 .filter(|item| item.contains(filter.as_str()))
+///                 .cloned()
+///                 .collect::<Vec<_>>()
+///         },
+///         (props.items.clone(), props.filter.clone()),
+///     );
+///
+///     html! {
+///         <ul>
+///             { for filtered.iter().map(|item| html! { <li>{ item }</li> }) }
+///         </ul>
+///     }
+/// }
+/// ```
+pub fn use_memo<T, F, D>(f: F, deps: D) -> Rc<T>
+where
+    T: 'static,
+    F: FnOnce(&D) -> T,
+    D: PartialEq + 'static,
+{
+    use_hook(
+        || -> Option<(D, Rc<T>)> { None },
+        move |state, _| match state {
+            Some((memo_deps, value)) if *memo_deps == deps => value.clone(),
+            _ => {
+                let value = Rc::new(f(&deps));
+                *state = Some((deps, value.clone()));
+                value
+            }
+        },
+        |_| {},
+    )
+}
@@ -0,0 +1,40 @@
+use crate::functional::use_hook;
+
+struct UseMount {
+    mounted: bool,
+}
+
+/// This hook is used for running a function once, after the component is rendered for the first
+/// time.
+///
+/// The function passed on the following renders is ignored. It is the equivalent of
+/// `use_effect_with_deps(move |_| { f(); || {} }, ())`.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[function_component(UseMount)]
+/// fn mount() -> Html {
+///     use_mount(|| yew::utils::document().set_title("Mounted"));
+///
+///     html! {
+///         <div>{ "Check the title of the page" }</div>
+///     }
+/// }
+/// ```
+pub fn use_mount(f: impl FnOnce() + 'static) {
+    use_hook(
+        || UseMount { mounted: false },
+        move |hook, updater| {
+            if !hook.mounted {
+                hook.mounted = true;
+                updater.post_render(move |_: &mut UseMount| {
+                    f();
+                    false
+                });
+            }
+        },
+        |_| {},
+    )
+}
@@ -0,0 +1,38 @@
+use crate::functional::use_hook;
+use crate::NodeRef;
+
+/// This hook is used for obtaining a [`NodeRef`] which stays the same across renders.
+///
+/// A `NodeRef` created with `NodeRef::default()` in the component body is a new reference on
+/// every render, so it can't be used to access the element outside of the render it was created
+/// in, for example in an effect or a callback.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use web_sys::HtmlInputElement;
+/// #
+/// #[function_component(UseNodeRef)]
+/// fn node_ref_hook() -> Html {
+///     let input_ref = use_node_ref();
+///
+///     let onclick = {
+///         let input_ref = input_ref.clone();
+///         Callback::from(move |_| {
+///             if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+///                 input.set_value("");
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <div>
+///             <input ref={input_ref} />
+///             <button {onclick}>{ "Clear" }</button>
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_node_ref() -> NodeRef {
+    use_hook(NodeRef::default, |node_ref, _| node_ref.clone(), |_| {})
+}
@@ -0,0 +1,36 @@
+use crate::functional::use_hook;
+
+struct UsePrevious<T> {
+    current: Option<T>,
+}
+
+/// This hook returns the value it was called with on the previous render, or `None` on the first
+/// render.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[derive(Properties, Clone, PartialEq)]
+/// pub struct Props {
+///     pub count: u32,
+/// }
+///
+/// #[function_component(UsePrevious)]
+/// fn previous(props: &Props) -> Html {
+///     let previous = use_previous(props.count);
+///
+///     html! {
+///         <div>
+///             { format!("Changed from {:?} to {}", previous, props.count) }
+///         </div>
+///     }
+/// }
+/// ```
+pub fn use_previous<T: 'static>(value: T) -> Option<T> {
+    use_hook(
+        || UsePrevious { current: None },
+        move |hook, _| hook.current.replace(value),
+        |_| {},
+    )
+}
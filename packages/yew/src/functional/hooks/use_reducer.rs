@@ -3,53 +3,119 @@ use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
 
-struct UseReducer<State> {
+/// A trait for states which are updated by actions, used by the [`use_reducer`] hook.
+///
+/// Keeping the reducer in a trait implementation, rather than a closure in the component, makes it
+/// possible to test it on its own and to share it between components.
+///
+/// # Example
+/// ```rust
+/// # use yew::functional::Reducible;
+/// # use std::rc::Rc;
+/// #
+/// enum CounterAction {
+///     Increment,
+///     Reset,
+/// }
+///
+/// struct CounterState {
+///     counter: i32,
+/// }
+///
+/// impl Reducible for CounterState {
+///     type Action = CounterAction;
+///
+///     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+///         let counter = match action {
+///             CounterAction::Increment => self.counter + 1,
+///             CounterAction::Reset => 0,
+///         };
+///         Self { counter }.into()
+///     }
+/// }
+///
+/// let state = Rc::new(CounterState { counter: 1 });
+/// let state = state.reduce(CounterAction::Increment);
+/// assert_eq!(state.counter, 2);
+/// ```
+pub trait Reducible {
+    /// The type of the actions updating the state
+    type Action;
+
+    /// Computes the new state from the current one and `action`.
+    ///
+    /// Returning `self` unchanged, instead of a new [`Rc`], tells [`use_reducer`] that the state
+    /// didn't change, and the component is not re-rendered.
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self>;
+}
+
+struct UseReducer<State, Action> {
+    current_state: Rc<State>,
+    /// Created on the first render, so the dispatcher stays the same for the lifetime of the
+    /// component
+    dispatch: Option<Rc<dyn Fn(Action)>>,
+}
+
+struct UseReducerWithInit<State, Action> {
     current_state: Rc<State>,
+    /// The reducer of the latest render, which is called by the dispatcher
+    reducer: Rc<dyn Fn(Rc<State>, Action) -> State>,
+    /// Created on the first render, so the dispatcher stays the same for the lifetime of the
+    /// component
+    dispatch: Option<Rc<dyn Fn(Action)>>,
 }
 
 /// This hook is an alternative to [`use_state`](super::use_state()). It is used to handle component's state and is used
 /// when complex actions needs to be performed on said state.
 ///
-/// For lazy initialization, consider using [`use_reducer_with_init`] instead.
+/// The state has to implement [`Reducible`], which computes the new state when an action is
+/// dispatched. The hook takes a function returning the initial state, which is only called on the
+/// first render.
+///
+/// The component is re-rendered when [`Reducible::reduce`] returns a new state. To pass the
+/// ability to dispatch actions to other components, use [`UseReducerHandle::dispatcher`]: unlike
+/// the handle, the dispatcher doesn't change when the state changes, so components receiving it
+/// as a prop are not re-rendered because of it.
 ///
 /// # Example
 /// ```rust
 /// # use yew::prelude::*;
 /// # use std::rc::Rc;
-/// # use std::ops::DerefMut;
 /// #
-/// #[function_component(UseReducer)]
-/// fn reducer() -> Html {
-///     /// reducer's Action
-///     enum Action {
-///         Double,
-///         Square,
-///     }
+/// /// reducer's Action
+/// enum Action {
+///     Double,
+///     Square,
+/// }
 ///
-///     /// reducer's State
-///     struct CounterState {
-///         counter: i32,
+/// /// reducer's State
+/// struct CounterState {
+///     counter: i32,
+/// }
+///
+/// impl Reducible for CounterState {
+///     type Action = Action;
+///
+///     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+///         let counter = match action {
+///             Action::Double => self.counter * 2,
+///             Action::Square => self.counter * self.counter,
+///         };
+///         Self { counter }.into()
 ///     }
+/// }
 ///
-///     let counter = use_reducer(
-///         // the reducer function
-///         |prev: Rc<CounterState>, action: Action| CounterState {
-///             counter: match action {
-///                 Action::Double => prev.counter * 2,
-///                 Action::Square => prev.counter * prev.counter,
-///             }
-///         },
-///         // initial state
-///         CounterState { counter: 1 },
-///     );
+/// #[function_component(UseReducer)]
+/// fn reducer() -> Html {
+///     let counter = use_reducer(|| CounterState { counter: 1 });
 ///
-///    let double_onclick = {
-///         let counter = counter.clone();
-///         Callback::from(move |_| counter.dispatch(Action::Double))
+///     let double_onclick = {
+///         let dispatcher = counter.dispatcher();
+///         Callback::from(move |_| dispatcher.dispatch(Action::Double))
 ///     };
 ///     let square_onclick = {
-///         let counter = counter.clone();
-///         Callback::from(move |_| counter.dispatch(Action::Square))
+///         let dispatcher = counter.dispatcher();
+///         Callback::from(move |_| dispatcher.dispatch(Action::Square))
 ///     };
 ///
 ///     html! {
@@ -62,19 +128,44 @@ struct UseReducer<State> {
 ///     }
 /// }
 /// ```
-pub fn use_reducer<Action, Reducer, State>(
-    reducer: Reducer,
-    initial_state: State,
-) -> UseReducerHandle<State, Action>
+pub fn use_reducer<State, InitFn>(init_fn: InitFn) -> UseReducerHandle<State, State::Action>
 where
-    Action: 'static,
-    Reducer: Fn(Rc<State>, Action) -> State + 'static,
-    State: 'static,
+    State: Reducible + 'static,
+    State::Action: 'static,
+    InitFn: FnOnce() -> State + 'static,
 {
-    use_reducer_with_init(reducer, initial_state, |a| a)
+    use_hook(
+        move || UseReducer {
+            current_state: Rc::new(init_fn()),
+            dispatch: None,
+        },
+        |s, updater| {
+            let dispatch = s.dispatch.get_or_insert_with(|| {
+                Rc::new(move |action: State::Action| {
+                    updater.callback(move |state: &mut UseReducer<State, State::Action>| {
+                        let new_state = Rc::clone(&state.current_state).reduce(action);
+                        let changed = !Rc::ptr_eq(&new_state, &state.current_state);
+                        state.current_state = new_state;
+                        changed
+                    });
+                })
+            });
+
+            UseReducerHandle {
+                value: Rc::clone(&s.current_state),
+                dispatch: Rc::clone(dispatch),
+            }
+        },
+        // The dispatcher holds the state of the hook, drop it to not leak the state
+        |s| s.dispatch = None,
+    )
 }
 
-/// [`use_reducer`] but with init argument.
+/// A reducer hook taking the reducer as a function instead of a [`Reducible`] state, and the
+/// initial state with an `init` function computing the state from it.
+///
+/// Actions are always reduced by the reducer passed on the latest render, so it can capture
+/// values like props, while the dispatcher stays the same.
 ///
 /// This is useful for lazy initialization where it is beneficial not to perform expensive
 /// computation up-front
@@ -121,42 +212,57 @@ where
     InitFn: Fn(InitialState) -> State + 'static,
 {
     let init = Box::new(init);
-    let reducer = Rc::new(reducer);
+    let reducer: Rc<dyn Fn(Rc<State>, Action) -> State> = Rc::new(reducer);
+    let initial_reducer = Rc::clone(&reducer);
     use_hook(
-        move || UseReducer {
+        move || UseReducerWithInit {
             current_state: Rc::new(init(initial_state)),
+            reducer: initial_reducer,
+            dispatch: None,
         },
         |s, updater| {
-            let setter: Rc<dyn Fn(Action)> = Rc::new(move |action: Action| {
-                let reducer = reducer.clone();
-                // We call the callback, consumer the updater
-                // Required to put the type annotations on Self so the method knows how to downcast
-                updater.callback(move |state: &mut UseReducer<State>| {
-                    let new_state = reducer(state.current_state.clone(), action);
-                    state.current_state = Rc::new(new_state);
-                    true
-                });
+            // The reducer may capture values of the current render
+            s.reducer = reducer;
+            let dispatch = s.dispatch.get_or_insert_with(|| {
+                Rc::new(move |action: Action| {
+                    // We call the callback, consumer the updater
+                    // Required to put the type annotations on Self so the method knows how to downcast
+                    updater.callback(move |state: &mut UseReducerWithInit<State, Action>| {
+                        let new_state = (state.reducer)(state.current_state.clone(), action);
+                        state.current_state = Rc::new(new_state);
+                        true
+                    });
+                })
             });
 
             UseReducerHandle {
                 value: Rc::clone(&s.current_state),
-                setter,
+                dispatch: Rc::clone(dispatch),
             }
         },
-        |_| {},
+        // The dispatcher holds the state of the hook, drop it to not leak the state
+        |s| s.dispatch = None,
     )
 }
 
 /// State handle for [`use_reducer`] hook
 pub struct UseReducerHandle<State, Action> {
     value: Rc<State>,
-    setter: Rc<dyn Fn(Action)>,
+    dispatch: Rc<dyn Fn(Action)>,
 }
 
 impl<State, Action> UseReducerHandle<State, Action> {
     /// Calls the dispatch with the given value
     pub fn dispatch(&self, value: Action) {
-        (self.setter)(value)
+        (self.dispatch)(value)
+    }
+
+    /// Returns the dispatcher of this reducer, which can dispatch actions without holding the
+    /// state.
+    pub fn dispatcher(&self) -> UseReducerDispatcher<Action> {
+        UseReducerDispatcher {
+            dispatch: Rc::clone(&self.dispatch),
+        }
     }
 }
 
@@ -172,7 +278,7 @@ impl<State, Action> Clone for UseReducerHandle<State, Action> {
     fn clone(&self) -> Self {
         Self {
             value: Rc::clone(&self.value),
-            setter: Rc::clone(&self.setter),
+            dispatch: Rc::clone(&self.dispatch),
         }
     }
 }
@@ -184,3 +290,45 @@ impl<T: fmt::Debug, V> fmt::Debug for UseReducerHandle<T, V> {
             .finish()
     }
 }
+
+/// Dispatcher handle for the [`use_reducer`] and [`use_reducer_with_init`] hooks.
+///
+/// It is cheap to clone and stays the same for the lifetime of the component, so it compares
+/// equal across renders and can be passed as a prop without re-rendering the receiving component
+/// when the state changes.
+pub struct UseReducerDispatcher<Action> {
+    dispatch: Rc<dyn Fn(Action)>,
+}
+
+impl<Action> UseReducerDispatcher<Action> {
+    /// Calls the dispatch with the given value
+    pub fn dispatch(&self, value: Action) {
+        (self.dispatch)(value)
+    }
+}
+
+impl<Action> Clone for UseReducerDispatcher<Action> {
+    fn clone(&self) -> Self {
+        Self {
+            dispatch: Rc::clone(&self.dispatch),
+        }
+    }
+}
+
+impl<Action> PartialEq for UseReducerDispatcher<Action> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.dispatch, &other.dispatch)
+    }
+}
+
+impl<Action> fmt::Debug for UseReducerDispatcher<Action> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseReducerDispatcher").finish()
+    }
+}
+
+impl<Action: 'static> From<UseReducerDispatcher<Action>> for crate::Callback<Action> {
+    fn from(dispatcher: UseReducerDispatcher<Action>) -> Self {
+        Self::from(move |action| dispatcher.dispatch(action))
+    }
+}
@@ -33,7 +33,7 @@ use std::{cell::RefCell, rc::Rc};
 ///     let onchange = {
 ///         let message = message.clone();
 ///           Callback::from(move |e| {
-///             if let ChangeData::Value(value) = e {
+///             if let ChangeData::Value(value, _) = e {
 ///                 message.set(value)
 ///             }
 ///         })
@@ -35,6 +35,47 @@ struct UseState<T2> {
 /// }
 /// ```
 pub fn use_state<T: 'static, F: FnOnce() -> T + 'static>(initial_state_fn: F) -> UseStateHandle<T> {
+    use_state_with_check(initial_state_fn, |_, _| true)
+}
+
+/// [`use_state`] but only re-renders the component when the new value is not equal to the
+/// current one.
+///
+/// This applies to every way of updating the state, so it can be used instead of calling
+/// [`UseStateHandle::set_if_neq`] everywhere.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[function_component(UseStateEq)]
+/// fn state() -> Html {
+///     let mode = use_state_eq(|| "light");
+///     let onclick = {
+///         let mode = mode.clone();
+///         // Clicking more than once does not re-render the component
+///         Callback::from(move |_| mode.set("dark"))
+///     };
+///
+///     html! {
+///         <button {onclick}>{ *mode }</button>
+///     }
+/// }
+/// ```
+pub fn use_state_eq<T, F>(initial_state_fn: F) -> UseStateHandle<T>
+where
+    T: PartialEq + 'static,
+    F: FnOnce() -> T + 'static,
+{
+    use_state_with_check(initial_state_fn, |current, new| current != new)
+}
+
+/// Creates the state of [`use_state`] and [`use_state_eq`]. A new value is only stored, and the
+/// component re-rendered, if `changed` returns `true` for the current and the new value.
+fn use_state_with_check<T: 'static, F: FnOnce() -> T + 'static>(
+    initial_state_fn: F,
+    changed: fn(&T, &T) -> bool,
+) -> UseStateHandle<T> {
     use_hook(
         // Initializer
         move || UseState {
@@ -42,10 +83,13 @@ pub fn use_state<T: 'static, F: FnOnce() -> T + 'static>(initial_state_fn: F) ->
         },
         // Runner
         move |hook, updater| {
-            let setter: Rc<(dyn Fn(T))> = Rc::new(move |new_val: T| {
-                updater.callback(move |st: &mut UseState<T>| {
-                    st.current = Rc::new(new_val);
-                    true
+            let setter: Rc<dyn Fn(StateUpdate<T>)> = Rc::new(move |update: StateUpdate<T>| {
+                updater.callback(move |st: &mut UseState<T>| match update(&st.current) {
+                    Some(new_val) if changed(&st.current, &new_val) => {
+                        st.current = Rc::new(new_val);
+                        true
+                    }
+                    _ => false,
                 })
             });
 
@@ -60,10 +104,13 @@ pub fn use_state<T: 'static, F: FnOnce() -> T + 'static>(initial_state_fn: F) ->
     )
 }
 
+/// Computes the new value of a state from the current one, or returns `None` to keep it.
+type StateUpdate<T> = Box<dyn FnOnce(&T) -> Option<T>>;
+
 /// State handle for the [`use_state`] hook.
 pub struct UseStateHandle<T> {
     value: Rc<T>,
-    setter: Rc<dyn Fn(T)>,
+    setter: Rc<dyn Fn(StateUpdate<T>)>,
 }
 
 impl<T: fmt::Debug> fmt::Debug for UseStateHandle<T> {
@@ -74,10 +121,62 @@ impl<T: fmt::Debug> fmt::Debug for UseStateHandle<T> {
     }
 }
 
-impl<T> UseStateHandle<T> {
+impl<T: 'static> UseStateHandle<T> {
     /// Updates the value
     pub fn set(&self, value: T) {
-        (self.setter)(value)
+        (self.setter)(Box::new(move |_| Some(value)))
+    }
+
+    /// Updates the value if it is not equal to the current one.
+    ///
+    /// The component is not re-rendered if the value is equal.
+    pub fn set_if_neq(&self, value: T)
+    where
+        T: PartialEq,
+    {
+        (self.setter)(Box::new(
+            move |current| {
+                if *current != value {
+                    Some(value)
+                } else {
+                    None
+                }
+            },
+        ))
+    }
+
+    /// Updates the value to the result of `f` called with the current value.
+    ///
+    /// Unlike computing the new value from the value of this handle, `f` is called with the value
+    /// at the time the update is applied, which includes all updates made before it. This makes
+    /// it safe to call multiple times before the component is re-rendered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use yew::prelude::*;
+    /// #
+    /// #[function_component(Counter)]
+    /// fn counter() -> Html {
+    ///     let counter = use_state(|| 0);
+    ///     let onclick = {
+    ///         let counter = counter.clone();
+    ///         Callback::from(move |_| {
+    ///             // Increments the counter by two
+    ///             counter.update(|prev| prev + 1);
+    ///             counter.update(|prev| prev + 1);
+    ///         })
+    ///     };
+    ///
+    ///     html! {
+    ///         <button {onclick}>{ *counter }</button>
+    ///     }
+    /// }
+    /// ```
+    pub fn update<F>(&self, f: F)
+    where
+        F: FnOnce(&T) -> T + 'static,
+    {
+        (self.setter)(Box::new(move |current| Some(f(current))))
     }
 }
 
@@ -0,0 +1,45 @@
+use crate::functional::use_hook;
+
+struct UseUnmount {
+    on_unmount: Option<Box<dyn FnOnce()>>,
+}
+
+/// This hook is used for running a function when the component is destroyed.
+///
+/// Only the function passed on the latest render is called, so it can use the latest props and
+/// state of the component.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[function_component(UseUnmount)]
+/// fn unmount() -> Html {
+///     let counter = use_state(|| 0);
+///
+///     let last_count = *counter;
+///     use_unmount(move || log::info!("Destroyed with a count of {}", last_count));
+///
+///     let onclick = {
+///         let counter = counter.clone();
+///         Callback::from(move |_| counter.set(*counter + 1))
+///     };
+///
+///     html! {
+///         <button {onclick}>{ *counter }</button>
+///     }
+/// }
+/// ```
+pub fn use_unmount(f: impl FnOnce() + 'static) {
+    use_hook(
+        || UseUnmount { on_unmount: None },
+        move |hook, _| {
+            hook.on_unmount = Some(Box::new(f));
+        },
+        |hook| {
+            if let Some(f) = hook.on_unmount.take() {
+                f();
+            }
+        },
+    )
+}
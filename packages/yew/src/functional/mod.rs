@@ -13,8 +13,9 @@
 //!
 //! More details about function components and Hooks can be found on [Yew Docs](https://yew.rs/next/concepts/function-components)
 
-use crate::html::AnyScope;
-use crate::{Component, ComponentLink, Html, Properties};
+use crate::html::{AnyScope, HtmlResult};
+use crate::suspense::{self, Suspense, SuspenseMsg, Suspension};
+use crate::{Callback, Component, ComponentLink, Html, Properties};
 use scoped_tls_hkt::scoped_thread_local;
 use std::cell::RefCell;
 use std::fmt;
@@ -25,7 +26,8 @@ pub use hooks::*;
 
 /// This attribute creates a function component from a normal Rust function.
 ///
-/// Functions with this attribute **must** return `Html` and can optionally take an argument for props.
+/// Functions with this attribute **must** return `Html`, or `HtmlResult` for components which can
+/// be suspended, and can optionally take an argument for props.
 /// Note that the function only receives a reference to the props.
 ///
 /// When using this attribute you need to provide a name for the component:
@@ -51,6 +53,52 @@ pub use hooks::*;
 /// ```
 pub use yew_macro::function_component;
 
+/// This attribute marks a function as a custom hook.
+///
+/// The name of a hook must start with `use_`. Hooks must be called in the same order on every
+/// render, so calling a hook (any function whose name starts with `use_`) in a conditional, a loop
+/// or a closure within the body of a hook or a function component is a compile error. So is
+/// calling a hook after an early `return` or `?`, except after a `?` in a function component
+/// returning `HtmlResult`, which suspends the component instead of returning early.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// #[hook]
+/// fn use_counter(initial: i32) -> (i32, Callback<MouseEvent>) {
+///     let counter = use_state(move || initial);
+///     let increment = {
+///         let counter = counter.clone();
+///         Callback::from(move |_| counter.set(*counter + 1))
+///     };
+///     (*counter, increment)
+/// }
+///
+/// #[function_component(Counter)]
+/// fn counter() -> Html {
+///     let (counter, onclick) = use_counter(0);
+///
+///     html! {
+///         <button {onclick}>{ counter }</button>
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use yew::prelude::*;
+/// #
+/// #[hook]
+/// fn use_optional_state(enabled: bool) -> Option<UseStateHandle<i32>> {
+///     if enabled {
+///         Some(use_state(|| 0))
+///     } else {
+///         None
+///     }
+/// }
+/// ```
+pub use yew_macro::hook;
+
 scoped_thread_local!(static mut CURRENT_HOOK: HookState);
 
 type Msg = Box<dyn FnOnce() -> bool>;
@@ -60,8 +108,11 @@ struct HookState {
     counter: usize,
     scope: AnyScope,
     process_message: ProcessMessage,
+    rendered_queue: MsgQueue,
     hooks: Vec<Rc<RefCell<dyn std::any::Any>>>,
     destroy_listeners: Vec<Box<dyn FnOnce()>>,
+    /// The suspension the component is re-rendered on when it resumes
+    suspension: Option<Suspension>,
 }
 
 /// Trait that allows a struct to act as Function Component.
@@ -82,6 +133,7 @@ pub struct FunctionComponent<T: FunctionProvider + 'static> {
     hook_state: RefCell<HookState>,
     link: ComponentLink<Self>,
     message_queue: MsgQueue,
+    rendered_queue: MsgQueue,
 }
 
 impl<T: FunctionProvider> fmt::Debug for FunctionComponent<T> {
@@ -111,15 +163,19 @@ where
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
         let scope = AnyScope::from(link.clone());
         let message_queue = MsgQueue::default();
+        let rendered_queue = MsgQueue::default();
 
         Self {
             _never: std::marker::PhantomData::default(),
             props,
             link: link.clone(),
             message_queue: message_queue.clone(),
+            rendered_queue: rendered_queue.clone(),
             hook_state: RefCell::new(HookState {
                 counter: 0,
+                suspension: None,
                 scope,
+                rendered_queue,
                 process_message: Rc::new(move |msg, post_render| {
                     if post_render {
                         message_queue.push(msg);
@@ -148,6 +204,17 @@ where
     }
 
     fn rendered(&mut self, _first_render: bool) {
+        // Callbacks of the rendered phase run synchronously, before the post render callbacks
+        // are processed as messages
+        let mut should_render = false;
+        for msg in self.rendered_queue.drain() {
+            should_render |= msg();
+        }
+        if should_render {
+            let msg: Msg = Box::new(|| true);
+            self.link.send_message(msg);
+        }
+
         for msg in self.message_queue.drain() {
             self.link.send_message(msg);
         }
@@ -169,6 +236,64 @@ pub(crate) fn get_current_scope() -> Option<AnyScope> {
     }
 }
 
+/// Trait for the types that function components can return: [`Html`] and [`HtmlResult`].
+pub trait IntoHtmlResult {
+    /// Converts this value into an [`HtmlResult`].
+    fn into_html_result(self) -> HtmlResult;
+}
+
+impl IntoHtmlResult for Html {
+    fn into_html_result(self) -> HtmlResult {
+        Ok(self)
+    }
+}
+
+impl IntoHtmlResult for HtmlResult {
+    fn into_html_result(self) -> HtmlResult {
+        self
+    }
+}
+
+/// Renders the body of a function component which can be suspended.
+///
+/// If `body` returns a [`Suspension`], the nearest [`Suspense`] renders its fallback and the
+/// component renders nothing until the suspension is resumed, at which point the component is
+/// rendered again. An error is logged if the component has no [`Suspense`] ancestor.
+///
+/// `#[function_component]` wraps the function body with this, so it only needs to be called from
+/// [`FunctionProvider::run`] implementations which are written by hand.
+pub fn render_suspendable<R: IntoHtmlResult>(body: impl FnOnce() -> R) -> Html {
+    match body().into_html_result() {
+        Ok(html) => html,
+        Err(suspension) => {
+            CURRENT_HOOK.with(|hook_state| {
+                match hook_state.scope.find_parent_scope::<Suspense>() {
+                    Some(suspense) => {
+                        suspense.send_message(SuspenseMsg::Suspend(suspension.clone()))
+                    }
+                    None => log::error!(
+                        "a function component was suspended without a Suspense ancestor, so it \
+                         renders nothing until it is resumed"
+                    ),
+                }
+                suspense::mark_rendering_suspended();
+
+                // The component may be rendered again, for example with new props, before the
+                // suspension is resumed, but it only needs to be re-rendered once
+                if hook_state.suspension.as_ref() != Some(&suspension) {
+                    hook_state.suspension = Some(suspension.clone());
+                    let process_message = hook_state.process_message.clone();
+                    suspension.listen(Callback::from(move |_| {
+                        process_message(Box::new(|| true), false);
+                    }));
+                }
+            });
+
+            Html::default()
+        }
+    }
+}
+
 #[derive(Clone, Default)]
 struct MsgQueue(Rc<RefCell<Vec<Msg>>>);
 
@@ -185,11 +310,14 @@ impl MsgQueue {
 /// The `HookUpdater` provides a convenient interface for hooking into the lifecycle of
 /// the underlying Yew Component that backs the function component.
 ///
-/// Two interfaces are provided - callback and post_render.
+/// Three interfaces are provided - callback, rendered and post_render.
 /// - `callback` allows the creation of regular yew callbacks on the host component.
+/// - `rendered` allows the creation of events that happen synchronously after the DOM of the
+///   component is updated.
 /// - `post_render` allows the creation of events that happen after a render is complete.
 ///
-/// See [`use_effect`](hooks::use_effect()) and [`use_context`](hooks::use_context())
+/// See [`use_effect`](hooks::use_effect()), [`use_layout_effect`](hooks::use_layout_effect())
+/// and [`use_context`](hooks::use_context())
 /// for more details on how to use the hook updater to provide function components
 /// the necessary callbacks to update the underlying state.
 #[derive(Clone)]
@@ -197,6 +325,7 @@ impl MsgQueue {
 pub struct HookUpdater {
     hook: Rc<RefCell<dyn std::any::Any>>,
     process_message: ProcessMessage,
+    rendered_queue: MsgQueue,
 }
 impl HookUpdater {
     /// Callback which runs the hook.
@@ -222,6 +351,24 @@ impl HookUpdater {
         );
     }
 
+    /// Callback called synchronously in the [`rendered`](Component::rendered) phase of the
+    /// component, after its DOM and the DOM of its children has been updated and before the
+    /// [`post_render`](Self::post_render) callbacks run. The component is rendered again if `cb`
+    /// returns `true`.
+    pub fn rendered<T: 'static, F>(&self, cb: F)
+    where
+        F: FnOnce(&mut T) -> bool + 'static,
+    {
+        let internal_hook_state = self.hook.clone();
+        self.rendered_queue.push(Box::new(move || {
+            let mut hook = internal_hook_state.borrow_mut();
+            let hook: &mut T = hook
+                .downcast_mut()
+                .expect("internal error: hook downcasted to wrong type");
+            cb(hook)
+        }));
+    }
+
     /// Callback called after the render
     pub fn post_render<T: 'static, F>(&self, cb: F)
     where
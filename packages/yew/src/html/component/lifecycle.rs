@@ -1,9 +1,11 @@
 //! Component lifecycle module
 
-use super::{Component, Scope};
+use super::{AnyScope, Component, Scope};
+use crate::error_boundary;
 use crate::scheduler::{self, Runnable, Shared};
 use crate::virtual_dom::{VDiff, VNode};
 use crate::NodeRef;
+use std::panic::{self, AssertUnwindSafe};
 use web_sys::Element;
 
 pub(crate) struct ComponentState<COMP: Component> {
@@ -11,7 +13,8 @@ pub(crate) struct ComponentState<COMP: Component> {
     pub(crate) root_node: VNode,
 
     scope: Scope<COMP>,
-    parent: Element,
+    /// `None` for components which are rendered to a string instead of the DOM
+    parent: Option<Element>,
     next_sibling: NodeRef,
     node_ref: NodeRef,
     has_rendered: bool,
@@ -21,16 +24,27 @@ pub(crate) struct ComponentState<COMP: Component> {
 
 impl<COMP: Component> ComponentState<COMP> {
     pub(crate) fn new(
-        parent: Element,
+        parent: Option<Element>,
         next_sibling: NodeRef,
         root_node: VNode,
         node_ref: NodeRef,
         scope: Scope<COMP>,
         props: COMP::Properties,
     ) -> Self {
-        let component = Box::new(COMP::create(props, scope.clone()));
+        let component = COMP::create(props, scope.clone());
+        Self::with_component(parent, next_sibling, root_node, node_ref, scope, component)
+    }
+
+    fn with_component(
+        parent: Option<Element>,
+        next_sibling: NodeRef,
+        root_node: VNode,
+        node_ref: NodeRef,
+        scope: Scope<COMP>,
+        component: COMP,
+    ) -> Self {
         Self {
-            component,
+            component: Box::new(component),
             root_node,
             scope,
             parent,
@@ -42,6 +56,25 @@ impl<COMP: Component> ComponentState<COMP> {
         }
     }
 
+    /// Moves the rendered nodes into `next_parent` and renders into it from now on.
+    pub(crate) fn shift(&mut self, next_parent: Element, next_sibling: NodeRef) {
+        if self.parent.is_some() {
+            self.root_node.shift(&next_parent, next_sibling.clone());
+            self.parent = Some(next_parent);
+            self.next_sibling = next_sibling;
+        }
+    }
+
+    /// Removes the rendered nodes after a lifecycle event of the component panicked, without
+    /// calling `destroy`.
+    fn tear_down(mut self) {
+        self.scope.unsubscribe_contexts();
+        if let Some(parent) = &self.parent {
+            self.root_node.detach(parent);
+        }
+        self.node_ref.set(None);
+    }
+
     fn drain_pending_updates(&mut self, state: &Shared<Option<ComponentState<COMP>>>) {
         if !self.pending_updates.is_empty() {
             scheduler::push_component_updates(self.pending_updates.drain(..).map(|update| {
@@ -93,6 +126,8 @@ pub(crate) enum UpdateEvent<COMP: Component> {
     MessageBatch(Vec<COMP::Message>),
     /// Wraps properties, node ref, and next sibling for a component.
     Properties(COMP::Properties, NodeRef, NodeRef),
+    /// A context the component is subscribed to has changed.
+    ContextChanged,
 }
 
 pub(crate) struct ComponentRunnable<COMP: Component> {
@@ -101,19 +136,69 @@ pub(crate) struct ComponentRunnable<COMP: Component> {
 }
 
 impl<COMP: Component> Runnable for ComponentRunnable<COMP> {
+    /// Panics of the component are passed to the nearest `ErrorBoundary`.
     fn run(self: Box<Self>) {
+        let scope: Option<AnyScope> = match &self.event {
+            ComponentLifecycleEvent::Create(event) => Some(event.scope.clone().into()),
+            _ => self
+                .state
+                .borrow()
+                .as_ref()
+                .map(|state| state.scope.clone().into()),
+        };
+
+        match scope {
+            Some(scope) => {
+                let state = self.state.clone();
+                // The panic released the borrow of the state while it unwound
+                let tear_down = move || {
+                    if let Some(state) = state.borrow_mut().take() {
+                        state.tear_down();
+                    }
+                };
+                error_boundary::run(&scope, || self.run_event(), tear_down);
+            }
+            None => self.run_event(),
+        }
+    }
+}
+
+impl<COMP: Component> ComponentRunnable<COMP> {
+    fn run_event(self) {
         let mut current_state = self.state.borrow_mut();
         match self.event {
             ComponentLifecycleEvent::Create(event) => {
                 if current_state.is_none() {
-                    *current_state = Some(ComponentState::new(
-                        event.parent,
-                        event.next_sibling,
-                        event.placeholder,
-                        event.node_ref,
-                        event.scope.clone(),
-                        event.props,
-                    ));
+                    let CreateEvent {
+                        parent,
+                        next_sibling,
+                        mut placeholder,
+                        node_ref,
+                        props,
+                        scope,
+                    } = event;
+                    let component = panic::catch_unwind(AssertUnwindSafe(|| {
+                        COMP::create(props, scope.clone())
+                    }));
+                    match component {
+                        Ok(component) => {
+                            *current_state = Some(ComponentState::with_component(
+                                Some(parent),
+                                next_sibling,
+                                placeholder,
+                                node_ref,
+                                scope,
+                                component,
+                            ));
+                        }
+                        Err(payload) => {
+                            // The component will never be rendered, so its siblings must not
+                            // use the placeholder as their next sibling.
+                            placeholder.detach(&parent);
+                            node_ref.set(None);
+                            panic::resume_unwind(payload);
+                        }
+                    }
                 }
             }
             ComponentLifecycleEvent::Update(event) => {
@@ -139,6 +224,7 @@ impl<COMP: Component> Runnable for ComponentRunnable<COMP> {
                             state.next_sibling = next_sibling;
                             state.component.change(props)
                         }
+                        UpdateEvent::ContextChanged => state.component.context_changed(),
                     };
 
                     if should_render {
@@ -149,13 +235,15 @@ impl<COMP: Component> Runnable for ComponentRunnable<COMP> {
             }
             ComponentLifecycleEvent::Render => {
                 if let Some(state) = current_state.as_mut() {
-                    if let Some(mut new_root) = state.pending_root.take() {
+                    if let (Some(mut new_root), Some(parent)) =
+                        (state.pending_root.take(), state.parent.as_ref())
+                    {
                         std::mem::swap(&mut new_root, &mut state.root_node);
                         let ancestor = Some(new_root);
                         let new_root = &mut state.root_node;
                         let scope = state.scope.clone().into();
                         let next_sibling = state.next_sibling.clone();
-                        let node = new_root.apply(&scope, &state.parent, next_sibling, ancestor);
+                        let node = new_root.apply(&scope, parent, next_sibling, ancestor);
                         state.node_ref.link(node);
                         state.scope.process(ComponentLifecycleEvent::Rendered);
                     }
@@ -172,7 +260,10 @@ impl<COMP: Component> Runnable for ComponentRunnable<COMP> {
             ComponentLifecycleEvent::Destroy => {
                 if let Some(mut state) = current_state.take() {
                     state.component.destroy();
-                    state.root_node.detach(&state.parent);
+                    state.scope.unsubscribe_contexts();
+                    if let Some(parent) = &state.parent {
+                        state.root_node.detach(parent);
+                    }
                     state.node_ref.set(None);
                 }
             }
@@ -84,6 +84,17 @@ pub trait Component: Sized + 'static {
     /// Components which don't have properties should always return false.
     fn change(&mut self, _props: Self::Properties) -> ShouldRender;
 
+    /// Called when a context the component consumes with
+    /// [`ComponentLink::consume_context`](Scope::consume_context) or
+    /// [`ComponentLink::consume_context_selector`](Scope::consume_context_selector) has changed.
+    /// The new value is returned by the next call of these methods.
+    ///
+    /// Components re-render by default, which is what most components need when they read the
+    /// context in `view`.
+    fn context_changed(&mut self) -> ShouldRender {
+        true
+    }
+
     /// Components define their visual layout using a JSX-style syntax through the use of the
     /// `html!` procedural macro. The full guide to using the macro can be found in [Yew's
     /// documentation](https://yew.rs/concepts/html).
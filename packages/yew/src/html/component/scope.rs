@@ -7,26 +7,34 @@ use super::{
     Component,
 };
 use crate::callback::Callback;
-use crate::context::{ContextHandle, ContextProvider};
+use crate::context::{ContextHandle, ContextProvider, SelectorSubscription};
+use crate::error_boundary;
 use crate::html::NodeRef;
 use crate::scheduler::{self, Shared};
 use crate::utils::document;
-use crate::virtual_dom::{insert_node, VNode};
+use crate::virtual_dom::{insert_node, VDiff, VNode};
 use std::any::{Any, TypeId};
 use std::cell::{Ref, RefCell};
+use std::collections::HashMap;
 use std::future::Future;
 use std::ops::Deref;
+use std::panic::{self, AssertUnwindSafe};
 use std::rc::Rc;
 use std::{fmt, iter};
 use wasm_bindgen_futures::spawn_local;
 use web_sys::{Element, Node};
 
+/// The context subscriptions of a component, by the types of the context and of the selector.
+/// The subscriptions are kept until the component is destroyed.
+type ContextSubscriptions = Rc<RefCell<HashMap<(TypeId, TypeId), Box<dyn Any>>>>;
+
 /// Untyped scope used for accessing parent scope
 #[derive(Debug, Clone)]
 pub struct AnyScope {
     type_id: TypeId,
     parent: Option<Rc<AnyScope>>,
     state: Rc<dyn Any>,
+    contexts: ContextSubscriptions,
 }
 
 impl<COMP: Component> From<Scope<COMP>> for AnyScope {
@@ -35,6 +43,7 @@ impl<COMP: Component> From<Scope<COMP>> for AnyScope {
             type_id: TypeId::of::<COMP>(),
             parent: scope.parent,
             state: scope.state,
+            contexts: scope.contexts,
         }
     }
 }
@@ -46,6 +55,7 @@ impl AnyScope {
             type_id: TypeId::of::<()>(),
             parent: None,
             state: Rc::new(()),
+            contexts: Rc::default(),
         }
     }
 
@@ -54,6 +64,11 @@ impl AnyScope {
         self.parent.as_deref()
     }
 
+    /// Returns whether both scopes belong to the same component
+    pub(crate) fn ptr_eq(&self, other: &AnyScope) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+
     /// Returns the type of the linked component
     pub fn get_type_id(&self) -> &TypeId {
         &self.type_id
@@ -67,10 +82,11 @@ impl AnyScope {
                 .state
                 .downcast::<RefCell<Option<ComponentState<COMP>>>>()
                 .expect("unexpected component type"),
+            contexts: self.contexts,
         }
     }
 
-    fn find_parent_scope<C: Component>(&self) -> Option<Scope<C>> {
+    pub(crate) fn find_parent_scope<C: Component>(&self) -> Option<Scope<C>> {
         let expected_type_id = TypeId::of::<C>();
         iter::successors(Some(self), |scope| scope.get_parent())
             .filter(|scope| scope.get_type_id() == &expected_type_id)
@@ -89,12 +105,33 @@ impl AnyScope {
         let component = scope.get_component()?;
         Some(component.subscribe_consumer(callback))
     }
+
+    /// Accesses the part selected by `selector` of a value provided by a parent `ContextProvider`
+    /// component of the same type.
+    ///
+    /// Unlike [`context`](Self::context), `callback` is only called when the selected value
+    /// changes.
+    pub fn context_with_selector<T, S, F>(
+        &self,
+        selector: F,
+        callback: Callback<S>,
+    ) -> Option<(S, ContextHandle<T>)>
+    where
+        T: Clone + PartialEq + 'static,
+        S: Clone + PartialEq + 'static,
+        F: Fn(&T) -> S + 'static,
+    {
+        let scope = self.find_parent_scope::<ContextProvider<T>>()?;
+        let component = scope.get_component()?;
+        Some(component.subscribe_consumer_with_selector(selector, callback))
+    }
 }
 
 pub(crate) trait Scoped {
     fn to_any(&self) -> AnyScope;
     fn root_vnode(&self) -> Option<Ref<'_, VNode>>;
     fn destroy(&mut self);
+    fn shift_node(&self, parent: Element, next_sibling: NodeRef);
 }
 
 impl<COMP: Component> Scoped for Scope<COMP> {
@@ -117,12 +154,21 @@ impl<COMP: Component> Scoped for Scope<COMP> {
     fn destroy(&mut self) {
         self.process(ComponentLifecycleEvent::Destroy);
     }
+
+    /// Moves the rendered nodes of a component into `parent`. This happens immediately, because
+    /// the caller continues to render into `parent`.
+    fn shift_node(&self, parent: Element, next_sibling: NodeRef) {
+        if let Some(state) = self.state.borrow_mut().as_mut() {
+            state.shift(parent, next_sibling);
+        }
+    }
 }
 
 /// A context which allows sending messages to a component.
 pub struct Scope<COMP: Component> {
     parent: Option<Rc<AnyScope>>,
     state: Shared<Option<ComponentState<COMP>>>,
+    contexts: ContextSubscriptions,
 }
 
 impl<COMP: Component> fmt::Debug for Scope<COMP> {
@@ -136,6 +182,7 @@ impl<COMP: Component> Clone for Scope<COMP> {
         Scope {
             parent: self.parent.clone(),
             state: self.state.clone(),
+            contexts: self.contexts.clone(),
         }
     }
 }
@@ -159,7 +206,11 @@ impl<COMP: Component> Scope<COMP> {
     pub(crate) fn new(parent: Option<AnyScope>) -> Self {
         let parent = parent.map(Rc::new);
         let state = Rc::new(RefCell::new(None));
-        Scope { parent, state }
+        Scope {
+            parent,
+            state,
+            contexts: Rc::default(),
+        }
     }
 
     /// Mounts a component with `props` to the specified `element` in the DOM.
@@ -188,6 +239,85 @@ impl<COMP: Component> Scope<COMP> {
         }));
     }
 
+    /// Creates a component with `props` and hydrates the DOM nodes starting at `fragment` with its
+    /// view.
+    ///
+    /// Unlike `mount_in_place`, the view is rendered immediately, because the nodes owned by the
+    /// component are only known once its view has adopted them. Must be called while the scheduler
+    /// is locked, so that messages are only processed after the whole tree has been hydrated.
+    pub(crate) fn hydrate_in_place(
+        &self,
+        parent: Element,
+        fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+        node_ref: NodeRef,
+        props: COMP::Properties,
+    ) {
+        let state = ComponentState::new(
+            Some(parent.clone()),
+            next_sibling.clone(),
+            VNode::default(),
+            node_ref.clone(),
+            self.clone(),
+            props,
+        );
+        *self.state.borrow_mut() = Some(state);
+
+        // The borrow must be released before hydrating the children, so they are able to access
+        // this component's context.
+        let root = self
+            .state
+            .borrow()
+            .as_ref()
+            .map(|state| state.component.view());
+        if let Some(mut root) = root {
+            // Descendants schedule their `Rendered` events on top of this one, so they are called
+            // first like after a regular render.
+            self.schedule(ComponentLifecycleEvent::Rendered);
+            let node = root.hydrate(&self.to_any(), &parent, fragment, next_sibling);
+            node_ref.link(node);
+            if let Some(state) = self.state.borrow_mut().as_mut() {
+                state.root_node = root;
+            }
+        }
+    }
+
+    /// Creates a component with `props` and writes the HTML of its view to `w` without touching
+    /// the DOM. The component is destroyed once its view has been rendered.
+    pub(crate) fn render_to_string(&self, w: &mut String, props: COMP::Properties) {
+        let state = ComponentState::new(
+            None,
+            NodeRef::default(),
+            VNode::default(),
+            NodeRef::default(),
+            self.clone(),
+            props,
+        );
+        *self.state.borrow_mut() = Some(state);
+
+        // The borrow must be released before rendering the children, so they are able to access
+        // this component's context.
+        let root = self
+            .state
+            .borrow()
+            .as_ref()
+            .map(|state| state.component.view());
+        if let Some(root) = root {
+            let scope = self.to_any();
+            let start = w.len();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| root.render_to_string(w, &scope)));
+            if let Err(payload) = result {
+                // The HTML of the children is replaced by the fallback of an error boundary
+                w.truncate(start);
+                error_boundary::render_fallback(&scope, w, payload);
+            }
+        }
+
+        if let Some(mut state) = self.state.borrow_mut().take() {
+            state.component.destroy();
+        }
+    }
+
     pub(crate) fn reuse(&self, props: COMP::Properties, node_ref: NodeRef, next_sibling: NodeRef) {
         self.process(UpdateEvent::Properties(props, node_ref, next_sibling).into());
     }
@@ -423,6 +553,81 @@ impl<COMP: Component> Scope<COMP> {
     ) -> Option<(T, ContextHandle<T>)> {
         self.to_any().context(callback)
     }
+
+    /// Returns the value provided by a parent `ContextProvider` component of the same type, or
+    /// `None` if there is no such provider.
+    ///
+    /// The first call subscribes the component to the context until it is destroyed. When the
+    /// context changes, [`Component::context_changed`] is called, which re-renders the component
+    /// by default. This makes it possible to read the context in [`Component::view`]:
+    ///
+    /// ```
+    ///# use yew::{html, Component, ComponentLink, Html, ShouldRender};
+    ///# #[derive(Clone, PartialEq)]
+    ///# struct Theme {
+    ///#     foreground: String,
+    ///# }
+    /// struct ThemedText {
+    ///     link: ComponentLink<Self>,
+    /// }
+    ///
+    /// impl Component for ThemedText {
+    ///#     type Message = ();
+    ///#     type Properties = ();
+    ///#     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self { Self { link } }
+    ///#     fn update(&mut self, _: Self::Message) -> ShouldRender { false }
+    ///#     fn change(&mut self, _: Self::Properties) -> ShouldRender { false }
+    ///     // ...
+    ///     fn view(&self) -> Html {
+    ///         let theme = self.link.consume_context::<Theme>().expect("no theme provided");
+    ///         html! {
+    ///             <p style={format!("color: {}", theme.foreground)}>{ "Themed text" }</p>
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn consume_context<T: Clone + PartialEq + 'static>(&self) -> Option<T> {
+        self.consume_context_selector(T::clone)
+    }
+
+    /// Returns the part selected by `selector` of a value provided by a parent `ContextProvider`
+    /// component of the same type, or `None` if there is no such provider.
+    ///
+    /// Like [`consume_context`](Self::consume_context), the first call subscribes the component
+    /// to the context, but [`Component::context_changed`] is only called when the selected value
+    /// changes. Each selector type, in practice each closure, has its own subscription, which
+    /// selects values with the selector of the latest call, so the selector may capture values.
+    pub fn consume_context_selector<T, S, F>(&self, selector: F) -> Option<S>
+    where
+        T: Clone + PartialEq + 'static,
+        S: Clone + PartialEq + 'static,
+        F: Fn(&T) -> S + 'static,
+    {
+        let key = (TypeId::of::<T>(), TypeId::of::<F>());
+        if let Some(subscription) = self.contexts.borrow().get(&key) {
+            return subscription
+                .downcast_ref::<SelectorSubscription<T, S>>()
+                .expect("unexpected context type")
+                .select(selector);
+        }
+
+        let scope = self.clone();
+        let callback = Callback::from(move |_: S| {
+            scope.process(UpdateEvent::ContextChanged.into());
+        });
+        let (selected, subscription) =
+            SelectorSubscription::subscribe(&self.to_any(), selector, callback)?;
+        self.contexts
+            .borrow_mut()
+            .insert(key, Box::new(subscription));
+        Some(selected)
+    }
+
+    /// Drops the subscriptions of the component to contexts.
+    pub(crate) fn unsubscribe_contexts(&self) {
+        let subscriptions = std::mem::take(&mut *self.contexts.borrow_mut());
+        drop(subscriptions);
+    }
 }
 
 /// Defines a message type that can be sent to a component.
@@ -7,28 +7,34 @@ macro_rules! impl_action {
             #[allow(unused_imports)]
             use crate::html::{listener::*, IntoPropValue};
             use crate::virtual_dom::Listener;
-            use gloo::events::{EventListener, EventListenerOptions};
             use wasm_bindgen::JsValue;
-            use web_sys::{$type as WebSysType, Element, EventTarget};
+            use web_sys::{$type as WebSysType, Element};
             use std::rc::Rc;
 
-            /// A wrapper for a callback which attaches event listeners to elements.
+            /// A wrapper for a callback which handles the events dispatched to elements.
             #[derive(Clone, Debug)]
             pub struct Wrapper {
                 callback: Callback<Event>,
+                options: ListenerOptions,
             }
 
             impl Wrapper {
                 /// Create a wrapper for an event-typed callback
                 pub fn new(callback: Callback<Event>) -> Self {
-                    Wrapper { callback }
+                    Self::new_with_options(callback, ListenerOptions::default_for($name))
+                }
+
+                /// Create a wrapper for an event-typed callback with listener options
+                pub fn new_with_options(callback: Callback<Event>, options: ListenerOptions) -> Self {
+                    Wrapper { callback, options }
                 }
 
                 #[doc(hidden)]
                 #[inline]
-                pub fn __macro_new(callback: impl IntoPropValue<Option<Callback<Event>>>) -> Option<Rc<dyn Listener>> {
-                    let callback = callback.into_prop_value()?;
-                    Some(Rc::new(Self::new(callback)))
+                pub fn __macro_new(callback: impl IntoPropValue<Option<WithOptions<Event>>>) -> Option<Rc<dyn Listener>> {
+                    let WithOptions { callback, options } = callback.into_prop_value()?;
+                    let options = options.unwrap_or_else(|| ListenerOptions::default_for($name));
+                    Some(Rc::new(Self::new_with_options(callback, options)))
                 }
             }
 
@@ -40,23 +46,17 @@ macro_rules! impl_action {
                     stringify!($action)
                 }
 
-                fn attach(&self, element: &Element) -> EventListener {
-                    let this = element.clone();
-                    let callback = self.callback.clone();
-                    let listener = move |
-                        event: &web_sys::Event
-                    | {
-                        let event: WebSysType = JsValue::from(event).into();
-                        callback.emit($convert(&this, event));
-                    };
-                    // We should only set passive event listeners for `touchstart` and `touchmove`.
-                    // See here: https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener#Improving_scrolling_performance_with_passive_listeners
-                    if $name == "touchstart" || $name == "touchmove" {
-                        EventListener::new(&EventTarget::from(element.clone()), $name, listener)
-                    } else {
-                        let options = EventListenerOptions::enable_prevent_default();
-                        EventListener::new_with_options(&EventTarget::from(element.clone()), $name, options, listener)
-                    }
+                fn event_name(&self) -> &'static str {
+                    $name
+                }
+
+                fn options(&self) -> ListenerOptions {
+                    self.options
+                }
+
+                fn handle(&self, element: &Element, event: web_sys::Event) {
+                    let event: WebSysType = JsValue::from(event).into();
+                    self.callback.emit($convert(element, event));
                 }
             }
         }
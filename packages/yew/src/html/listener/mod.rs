@@ -2,10 +2,16 @@
 mod macros;
 mod events;
 
+use super::IntoPropValue;
+use crate::callback::Callback;
+use crate::virtual_dom::Listener;
+use std::any::type_name;
+use std::fmt;
+use std::rc::Rc;
 use wasm_bindgen::JsCast;
 use web_sys::{
-    Element, FileList, HtmlInputElement as InputElement, HtmlSelectElement as SelectElement,
-    HtmlTextAreaElement as TextAreaElement, InputEvent,
+    Element, Event, EventTarget, FileList, HtmlInputElement as InputElement, HtmlOptionElement,
+    HtmlSelectElement as SelectElement, HtmlTextAreaElement as TextAreaElement, InputEvent,
 };
 
 pub use events::*;
@@ -20,9 +26,41 @@ pub struct InputData {
     pub event: InputEvent,
 }
 
+impl InputData {
+    fn input(&self) -> Option<InputElement> {
+        self.event.target_dyn_into()
+    }
+
+    /// Returns whether the checkbox or radio button is checked, or `None` if the element is not
+    /// an `<input>`.
+    pub fn checked(&self) -> Option<bool> {
+        self.input().map(|input| input.checked())
+    }
+
+    /// Returns the value of a number or range input as a number, or `None` if the element is not
+    /// an `<input>` or its value is not a number.
+    pub fn value_as_number(&self) -> Option<f64> {
+        self.input()
+            .map(|input| input.value_as_number())
+            .filter(|value| !value.is_nan())
+    }
+
+    /// Returns the value of a date or time input as a date, or `None` if the element is not an
+    /// `<input>` or its value is not a date.
+    pub fn value_as_date(&self) -> Option<js_sys::Date> {
+        self.input()
+            .and_then(|input| input.value_as_date().ok().flatten())
+    }
+
+    /// Returns the files selected in a file input, or `None` if the element is not an `<input>`.
+    pub fn files(&self) -> Option<FileList> {
+        self.input().and_then(|input| input.files())
+    }
+}
+
 // There is no '.../Web/API/ChangeEvent/data' (for onchange) similar to
 // https://developer.mozilla.org/en-US/docs/Web/API/InputEvent/data (for oninput).
-// ChangeData actually contains the value of the InputElement/TextAreaElement
+// ChangeData actually contains the value and the InputElement/TextAreaElement
 // after `change` event occured or contains the SelectElement (see more at the
 // variant ChangeData::Select)
 
@@ -30,17 +68,378 @@ pub struct InputData {
 /// ([onchange event](https://developer.mozilla.org/en-US/docs/Web/Events/change)).
 #[derive(Debug)]
 pub enum ChangeData {
-    /// Value of the element in cases of `<input>`, `<textarea>`
-    Value(String),
+    /// Value of the element in cases of `<input>`, `<textarea>`, and the element itself.
+    /// The state of a checkbox or radio button is returned by [`checked`](ChangeData::checked).
+    Value(String, Element),
     /// SelectElement in case of `<select>` element. You can use one of methods of SelectElement
     /// to collect your required data such as `value` and `selected_index`.
-    /// You can also iterate throught `selected_options` yourself, this does require adding the
-    /// [web-sys](https://crates.io/crates/web-sys) crate with the `HtmlCollection` feature.
+    /// The selected options of a `<select multiple>` are returned by
+    /// [`selected_options`](ChangeData::selected_options).
     Select(SelectElement),
     /// Files
     Files(FileList),
 }
 
+/// Options of an event listener.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ListenerOptions {
+    /// A passive listener can not prevent the default action of the event, which lets the
+    /// browser handle events like `scroll` or `touchmove` without waiting for it.
+    pub passive: bool,
+    /// A capturing listener is called before the listeners of the descendants of its element.
+    pub capture: bool,
+}
+
+impl ListenerOptions {
+    /// Returns the options of a listener of `event_name` which were not set explicitly.
+    ///
+    /// Only listeners of `touchstart` and `touchmove` are passive by default.
+    /// See here: https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener#Improving_scrolling_performance_with_passive_listeners
+    pub fn default_for(event_name: &str) -> Self {
+        Self {
+            passive: event_name == "touchstart" || event_name == "touchmove",
+            capture: false,
+        }
+    }
+}
+
+/// A [Callback] for an event listener together with its [ListenerOptions].
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use yew::html::WithOptions;
+/// #
+/// # fn view(onscroll: Callback<Event>, onfocus: Callback<FocusEvent>) -> Html {
+/// html! {
+///     <div onscroll={WithOptions::passive(onscroll)}>
+///         <input onfocus={WithOptions::capture(onfocus)} />
+///     </div>
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WithOptions<E> {
+    pub(crate) callback: Callback<E>,
+    pub(crate) options: Option<ListenerOptions>,
+}
+
+impl<E> WithOptions<E> {
+    /// Creates a listener callback with the given options.
+    pub fn new(callback: impl Into<Callback<E>>, options: ListenerOptions) -> Self {
+        Self {
+            callback: callback.into(),
+            options: Some(options),
+        }
+    }
+
+    /// Creates a passive listener callback.
+    pub fn passive(callback: impl Into<Callback<E>>) -> Self {
+        Self::new(
+            callback,
+            ListenerOptions {
+                passive: true,
+                capture: false,
+            },
+        )
+    }
+
+    /// Creates a capturing listener callback.
+    pub fn capture(callback: impl Into<Callback<E>>) -> Self {
+        Self::new(
+            callback,
+            ListenerOptions {
+                passive: false,
+                capture: true,
+            },
+        )
+    }
+}
+
+impl<E> Clone for WithOptions<E> {
+    fn clone(&self) -> Self {
+        Self {
+            callback: self.callback.clone(),
+            options: self.options,
+        }
+    }
+}
+
+impl<E> From<Callback<E>> for WithOptions<E> {
+    fn from(callback: Callback<E>) -> Self {
+        Self {
+            callback,
+            options: None,
+        }
+    }
+}
+
+impl<E> IntoPropValue<Option<WithOptions<E>>> for Callback<E> {
+    fn into_prop_value(self) -> Option<WithOptions<E>> {
+        Some(self.into())
+    }
+}
+
+impl<E> IntoPropValue<Option<WithOptions<E>>> for &Callback<E> {
+    fn into_prop_value(self) -> Option<WithOptions<E>> {
+        Some(self.clone().into())
+    }
+}
+
+impl<E> IntoPropValue<Option<WithOptions<E>>> for Option<Callback<E>> {
+    fn into_prop_value(self) -> Option<WithOptions<E>> {
+        self.map(WithOptions::from)
+    }
+}
+
+impl<E> IntoPropValue<Option<WithOptions<E>>> for &Option<Callback<E>> {
+    fn into_prop_value(self) -> Option<WithOptions<E>> {
+        self.clone().map(WithOptions::from)
+    }
+}
+
+impl<E> IntoPropValue<Option<WithOptions<E>>> for &WithOptions<E> {
+    fn into_prop_value(self) -> Option<WithOptions<E>> {
+        Some(self.clone())
+    }
+}
+
+/// A listener of an event with an arbitrary name, like a custom event fired by a web component.
+///
+/// It is created by `on:event-name={callback}` in `html!`. The event is passed to the callback as
+/// `E`, which is usually [CustomEvent](web_sys::CustomEvent) or [Event](web_sys::Event). If the
+/// event is not an `E`, an error is logged instead. Unlike other listeners, it is attached to its
+/// element, so it also receives events which don't bubble.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use web_sys::CustomEvent;
+/// #
+/// # fn view(onpicked: Callback<CustomEvent>) -> Html {
+/// html! {
+///     <color-picker on:color-picked={onpicked} />
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CustomListener<E> {
+    event_name: &'static str,
+    callback: Callback<E>,
+    options: ListenerOptions,
+}
+
+impl<E: JsCast + 'static> CustomListener<E> {
+    /// Creates a listener of `event_name`.
+    pub fn new(event_name: &'static str, callback: Callback<E>, options: ListenerOptions) -> Self {
+        Self {
+            event_name,
+            callback,
+            options,
+        }
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn __macro_new(
+        event_name: &'static str,
+        callback: impl IntoPropValue<Option<WithOptions<E>>>,
+    ) -> Option<Rc<dyn Listener>> {
+        let WithOptions { callback, options } = callback.into_prop_value()?;
+        let options = options.unwrap_or_else(|| ListenerOptions::default_for(event_name));
+        Some(Rc::new(Self::new(event_name, callback, options)))
+    }
+}
+
+impl<E: JsCast + 'static> Listener for CustomListener<E> {
+    fn kind(&self) -> &'static str {
+        self.event_name
+    }
+
+    fn event_name(&self) -> &'static str {
+        self.event_name
+    }
+
+    fn options(&self) -> ListenerOptions {
+        self.options
+    }
+
+    fn handle(&self, _element: &Element, event: web_sys::Event) {
+        match event.dyn_into() {
+            Ok(event) => self.callback.emit(event),
+            Err(_) => log::error!(
+                "the listener of `{}` expected a `{}`, so the event was not passed to it",
+                self.event_name,
+                short_type_name::<E>()
+            ),
+        }
+    }
+
+    /// Custom events often don't bubble and are not composed, so they would not reach the element
+    /// the application is mounted in when they are dispatched inside a shadow root.
+    fn delegated(&self) -> bool {
+        false
+    }
+}
+
+impl ChangeData {
+    fn input(&self) -> Option<&InputElement> {
+        match self {
+            Self::Value(_, element) => element.dyn_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an `<input>` or `<textarea>`, or the value of the first selected
+    /// option of a `<select>`.
+    pub fn value(&self) -> Option<String> {
+        match self {
+            Self::Value(value, _) => Some(value.clone()),
+            Self::Select(select) => Some(select.value()),
+            Self::Files(_) => None,
+        }
+    }
+
+    /// Returns whether the checkbox or radio button is checked, or `None` if the element is not
+    /// an `<input>`.
+    pub fn checked(&self) -> Option<bool> {
+        self.input().map(|input| input.checked())
+    }
+
+    /// Returns the value of a number or range input as a number, or `None` if the element is not
+    /// an `<input>` or its value is not a number.
+    pub fn value_as_number(&self) -> Option<f64> {
+        self.input()
+            .map(|input| input.value_as_number())
+            .filter(|value| !value.is_nan())
+    }
+
+    /// Returns the value of a date or time input as a date, or `None` if the element is not an
+    /// `<input>` or its value is not a date.
+    pub fn value_as_date(&self) -> Option<js_sys::Date> {
+        self.input()
+            .and_then(|input| input.value_as_date().ok().flatten())
+    }
+
+    /// Returns the selected options of a `<select>`, which are multiple ones if it has the
+    /// `multiple` attribute.
+    pub fn selected_options(&self) -> Vec<HtmlOptionElement> {
+        match self {
+            Self::Select(select) => {
+                let options = select.selected_options();
+                (0..options.length())
+                    .filter_map(|i| options.item(i))
+                    .map(JsCast::unchecked_into)
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Returns the values of the selected options of a `<select>`.
+    pub fn selected_values(&self) -> Vec<String> {
+        self.selected_options()
+            .iter()
+            .map(HtmlOptionElement::value)
+            .collect()
+    }
+
+    /// Returns the files selected in a file input.
+    pub fn files(&self) -> Option<&FileList> {
+        match self {
+            Self::Files(files) => Some(files),
+            _ => None,
+        }
+    }
+}
+
+/// Casts the target of an event to a concrete element type.
+///
+/// It is implemented for all event types.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// use web_sys::HtmlInputElement;
+///
+/// let onclick = Callback::from(|e: MouseEvent| {
+///     let input: HtmlInputElement = e.target_into().unwrap();
+///     log::info!("checked: {}", input.checked());
+/// });
+/// ```
+pub trait TargetCast: AsRef<Event> {
+    /// Returns the target of the event as `T`, or an error naming the expected and the actual
+    /// type if the target is not a `T`.
+    fn target_into<T: JsCast>(&self) -> Result<T, TargetCastError> {
+        let target = self.as_ref().target();
+        target
+            .clone()
+            .and_then(|target| target.dyn_into().ok())
+            .ok_or_else(|| TargetCastError {
+                expected: short_type_name::<T>(),
+                found: target.map(|target| target_name(&target)),
+            })
+    }
+
+    /// Returns the target of the event as `T`, or `None` if it is not a `T`.
+    fn target_dyn_into<T: JsCast>(&self) -> Option<T> {
+        self.target_into().ok()
+    }
+
+    /// Returns the target of the event as `T` without checking its type.
+    ///
+    /// Only use it if the target can only be a `T`, e.g. in a listener of an element without
+    /// children.
+    fn target_unchecked_into<T: JsCast>(&self) -> T {
+        self.as_ref()
+            .target()
+            .expect("event has no target")
+            .unchecked_into()
+    }
+}
+
+impl<E: AsRef<Event>> TargetCast for E {}
+
+/// The error returned by [TargetCast::target_into] if the target of an event is not of the
+/// expected type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetCastError {
+    expected: &'static str,
+    found: Option<String>,
+}
+
+impl fmt::Display for TargetCastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.found {
+            Some(found) => write!(
+                f,
+                "expected the event target to be a `{}`, but found {}",
+                self.expected, found
+            ),
+            None => write!(
+                f,
+                "expected the event target to be a `{}`, but the event has no target",
+                self.expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TargetCastError {}
+
+/// Returns the name of `T` without the module path, which for web-sys types is an implementation
+/// detail.
+fn short_type_name<T>() -> &'static str {
+    type_name::<T>().rsplit("::").next().unwrap_or_default()
+}
+
+fn target_name(target: &EventTarget) -> String {
+    match target.dyn_ref::<Element>() {
+        Some(element) => format!("<{}>", element.local_name()),
+        None => "a target which is not an element".to_string(),
+    }
+}
+
 fn oninput_handler(this: &Element, event: InputEvent) -> InputData {
     // Normally only InputElement or TextAreaElement can have an oninput event listener. In
     // practice though any element with `contenteditable=true` may generate such events,
@@ -68,12 +467,12 @@ fn onchange_handler(this: &Element) -> ChangeData {
                 let files: FileList = input.files().unwrap();
                 ChangeData::Files(files)
             } else {
-                ChangeData::Value(input.value())
+                ChangeData::Value(input.value(), this.clone())
             }
         }
         "TEXTAREA" => {
             let tae = this.dyn_ref::<TextAreaElement>().unwrap();
-            ChangeData::Value(tae.value())
+            ChangeData::Value(tae.value(), this.clone())
         }
         "SELECT" => {
             let se = this.dyn_ref::<SelectElement>().unwrap().clone();
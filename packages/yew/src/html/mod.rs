@@ -10,15 +10,38 @@ pub use component::*;
 pub use conversion::*;
 pub use listener::*;
 
-use crate::virtual_dom::VNode;
+use crate::suspense::Suspension;
+use crate::virtual_dom::{VNode, VPortal};
 use std::cell::RefCell;
 use std::rc::Rc;
-use wasm_bindgen::JsValue;
-use web_sys::Node;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CustomEvent, CustomEventInit, Element, Node};
 
 /// A type which expected as a result of `view` function implementation.
 pub type Html = VNode;
 
+/// A type which is expected as a result of function components which can be suspended.
+pub type HtmlResult = Result<Html, Suspension>;
+
+/// Creates a portal which renders `child` into `host` instead of the parent element of the portal.
+///
+/// The child stays at the position of the portal in the component tree, so it keeps receiving the
+/// contexts of its ancestors. Its DOM nodes are removed from `host` when the portal is removed.
+///
+/// # Example
+/// ```
+/// # use yew::prelude::*;
+/// #
+/// #[function_component(Modal)]
+/// fn modal() -> Html {
+///     let host = yew::utils::document().body().unwrap().into();
+///     create_portal(html! { <div class="modal">{ "Hello" }</div> }, host)
+/// }
+/// ```
+pub fn create_portal(child: Html, host: Element) -> Html {
+    VNode::VPortal(VPortal::new(child, host))
+}
+
 /// Wrapped Node reference for later use in Component lifecycle methods.
 ///
 /// # Example
@@ -104,6 +127,47 @@ impl NodeRef {
         node.map(Into::into).map(INTO::from)
     }
 
+    /// Dispatches a bubbling [CustomEvent] named `event_name` with `detail` to the referenced
+    /// node. The event can be handled with an `on:event-name` listener in `html!`.
+    ///
+    /// Returns `false` if there is no node or a listener prevented the default action of the
+    /// event.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use yew::prelude::*;
+    /// # use wasm_bindgen::JsValue;
+    /// #
+    /// #[function_component(Picker)]
+    /// fn picker() -> Html {
+    ///     let node_ref = use_ref(NodeRef::default).borrow().clone();
+    ///     let onclick = {
+    ///         let node_ref = node_ref.clone();
+    ///         Callback::from(move |_| {
+    ///             node_ref.dispatch_custom_event("picked", &JsValue::from_str("red"));
+    ///         })
+    ///     };
+    ///
+    ///     html! {
+    ///         <button ref={node_ref} {onclick}>{ "Pick red" }</button>
+    ///     }
+    /// }
+    /// ```
+    pub fn dispatch_custom_event(&self, event_name: &str, detail: &JsValue) -> bool {
+        let node = match self.get() {
+            Some(node) => node,
+            None => return false,
+        };
+        let init = CustomEventInit::new();
+        init.set_bubbles(true);
+        init.set_cancelable(true);
+        init.set_detail(detail);
+        let event = CustomEvent::new_with_event_init_dict(event_name, &init)
+            .expect("failed to create custom event");
+        node.dispatch_event(event.unchecked_ref())
+            .expect("failed to dispatch custom event")
+    }
+
     /// Wrap an existing `Node` in a `NodeRef`
     pub(crate) fn new(node: Node) -> Self {
         let node_ref = NodeRef::default();
@@ -11,6 +11,7 @@
 //!
 //! ### Supported Targets
 //! - `wasm32-unknown-unknown`
+//! - Any target for rendering components to HTML strings with [`ServerRenderer`]
 //!
 //! ### Important Notes
 //! - Yew is not (yet) production ready but is great for side projects and internal tools
@@ -269,9 +270,12 @@ pub mod macros {
 mod app_handle;
 pub mod callback;
 pub mod context;
+pub mod error_boundary;
 pub mod functional;
 pub mod html;
 pub mod scheduler;
+mod server_renderer;
+pub mod suspense;
 pub mod utils;
 pub mod virtual_dom;
 
@@ -279,16 +283,18 @@ pub use web_sys;
 
 /// The module that contains all events available in the framework.
 pub mod events {
-    pub use crate::html::{ChangeData, InputData};
+    pub use crate::html::{ChangeData, InputData, TargetCast, TargetCastError};
 
     #[doc(no_inline)]
     pub use web_sys::{
-        AnimationEvent, DragEvent, ErrorEvent, Event, FocusEvent, InputEvent, KeyboardEvent,
-        MouseEvent, PointerEvent, ProgressEvent, TouchEvent, TransitionEvent, UiEvent, WheelEvent,
+        AnimationEvent, CustomEvent, DragEvent, ErrorEvent, Event, FocusEvent, InputEvent,
+        KeyboardEvent, MouseEvent, PointerEvent, ProgressEvent, TouchEvent, TransitionEvent,
+        UiEvent, WheelEvent,
     };
 }
 
 pub use crate::app_handle::AppHandle;
+pub use crate::server_renderer::ServerRenderer;
 use web_sys::Element;
 
 thread_local! {
@@ -355,6 +361,34 @@ where
     AppHandle::<COMP>::mount_with_props(element, props)
 }
 
+/// Starts a Yew application in an element which already contains the markup rendered by
+/// [`ServerRenderer`] for the same component and properties.
+/// If you would like to pass props, use the `hydrate_app_with_props_in_element` method.
+pub fn hydrate_app_in_element<COMP>(element: Element) -> AppHandle<COMP>
+where
+    COMP: Component,
+    COMP::Properties: Default,
+{
+    hydrate_app_with_props_in_element(element, COMP::Properties::default())
+}
+
+/// Starts a Yew application in an element which already contains the markup rendered by
+/// [`ServerRenderer`] for the same component and properties.
+///
+/// Instead of recreating the DOM, the existing nodes are adopted by the virtual DOM and listeners
+/// are attached to them. Nodes which do not match are replaced and, in debug builds, the
+/// mismatches are logged.
+pub fn hydrate_app_with_props_in_element<COMP>(
+    element: Element,
+    props: COMP::Properties,
+) -> AppHandle<COMP>
+where
+    COMP: Component,
+{
+    set_default_panic_hook();
+    AppHandle::<COMP>::hydrate_with_props(element, props)
+}
+
 /// The main entry point of a Yew application.
 /// This function does the same as `start_app(...)` but allows to start an Yew application with properties.
 pub fn start_app_with_props<COMP>(props: COMP::Properties) -> AppHandle<COMP>
@@ -395,12 +429,14 @@ pub mod prelude {
     pub use crate::app_handle::AppHandle;
     pub use crate::callback::Callback;
     pub use crate::context::ContextProvider;
+    pub use crate::error_boundary::ErrorBoundary;
     pub use crate::events::*;
     pub use crate::html::{
-        Children, ChildrenWithProps, Classes, Component, ComponentLink, Html, NodeRef, Properties,
-        ShouldRender,
+        create_portal, Children, ChildrenWithProps, Classes, Component, ComponentLink, Html,
+        HtmlResult, NodeRef, Properties, ShouldRender,
     };
     pub use crate::macros::{classes, html, html_nested};
+    pub use crate::suspense::Suspense;
 
     pub use crate::functional::*;
 }
@@ -89,14 +89,14 @@ pub(crate) fn push_component_updates(it: impl IntoIterator<Item = Box<dyn Runnab
     with(|s| s.update.extend(it));
 }
 
+thread_local! {
+    // The lock is used to prevent recursion. If the lock cannot be acquired, it is because the
+    // `start()` method is being called recursively as part of a `runnable.run()`.
+    static LOCK: RefCell<()> = Default::default();
+}
+
 /// Execute any pending Runnables
 pub(crate) fn start() {
-    thread_local! {
-        // The lock is used to prevent recursion. If the lock cannot be acquired, it is because the
-        // `start()` method is being called recursively as part of a `runnable.run()`.
-        static LOCK: RefCell<()> = Default::default();
-    }
-
     LOCK.with(|l| {
         if let Ok(_lock) = l.try_borrow_mut() {
             while let Some(runnable) = SCHEDULER.with(|s| s.borrow_mut().next_runnable()) {
@@ -106,6 +106,19 @@ pub(crate) fn start() {
     });
 }
 
+/// Execute `f` while holding the scheduler lock.
+///
+/// Runnables scheduled by `f` are not executed until `f` returns, the same way as they would be
+/// deferred if they were scheduled from inside a running Runnable.
+pub(crate) fn locked<R>(f: impl FnOnce() -> R) -> R {
+    let result = LOCK.with(|l| {
+        let _lock = l.try_borrow_mut();
+        f()
+    });
+    start();
+    result
+}
+
 impl Scheduler {
     /// Pop next Runnable to be executed according to Runnable type execution priority
     fn next_runnable(&mut self) -> Option<Box<dyn Runnable>> {
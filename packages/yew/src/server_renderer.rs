@@ -0,0 +1,267 @@
+//! This module contains the `ServerRenderer` struct, which is used to render a component to an
+//! HTML string without a browser.
+
+use crate::html::{Component, Scope};
+use crate::scheduler;
+use std::fmt;
+
+/// A renderer which creates a component and renders its view, including the views of all
+/// descendant components, into an HTML string.
+///
+/// No DOM APIs are used during rendering, so it works on any target, including native servers.
+/// Components are created and rendered once; their `rendered` method is never called and effects
+/// of function components are not run. Listeners are not part of the output, and neither are
+/// nodes referencing DOM nodes (`VNode::VRef`).
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// use yew::ServerRenderer;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! { <div class="greeting">{ "Hello, World!" }</div> }
+/// }
+///
+/// let rendered = ServerRenderer::<App>::new().render();
+/// assert_eq!(rendered, r#"<div class="greeting">Hello, World!</div>"#);
+/// ```
+pub struct ServerRenderer<COMP: Component> {
+    props: COMP::Properties,
+}
+
+impl<COMP: Component> fmt::Debug for ServerRenderer<COMP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ServerRenderer<_>")
+    }
+}
+
+impl<COMP> Default for ServerRenderer<COMP>
+where
+    COMP: Component,
+    COMP::Properties: Default,
+{
+    fn default() -> Self {
+        Self::with_props(COMP::Properties::default())
+    }
+}
+
+impl<COMP> ServerRenderer<COMP>
+where
+    COMP: Component,
+    COMP::Properties: Default,
+{
+    /// Creates a renderer for a component with default properties.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<COMP: Component> ServerRenderer<COMP> {
+    /// Creates a renderer for a component with the given properties.
+    pub fn with_props(props: COMP::Properties) -> Self {
+        Self { props }
+    }
+
+    /// Renders the component to a new `String`.
+    pub fn render(self) -> String {
+        let mut s = String::new();
+        self.render_to_string(&mut s);
+        s
+    }
+
+    /// Renders the component and appends the result to `w`.
+    pub fn render_to_string(self, w: &mut String) {
+        let scope: Scope<COMP> = Scope::new(None);
+        // Messages sent while rendering are only processed after all components have been
+        // rendered and destroyed, so they do not change the output.
+        scheduler::locked(|| scope.render_to_string(w, self.props));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::ContextProvider;
+    use crate::functional::{use_context, use_state};
+    use crate::{html, Children, ComponentLink, Html, Properties, ShouldRender};
+
+    #[cfg(feature = "wasm_test")]
+    use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+    #[cfg(feature = "wasm_test")]
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Clone, PartialEq, Properties)]
+    struct CardProps {
+        title: String,
+        #[prop_or_default]
+        children: Children,
+    }
+
+    struct Card {
+        props: CardProps,
+    }
+
+    impl Component for Card {
+        type Message = ();
+        type Properties = CardProps;
+
+        fn create(props: Self::Properties, _: ComponentLink<Self>) -> Self {
+            Self { props }
+        }
+
+        fn update(&mut self, _: Self::Message) -> ShouldRender {
+            false
+        }
+
+        fn change(&mut self, _: Self::Properties) -> ShouldRender {
+            false
+        }
+
+        fn view(&self) -> Html {
+            html! {
+                <section>
+                    <h1 title={self.props.title.clone()}>{ &self.props.title }</h1>
+                    { self.props.children.clone() }
+                </section>
+            }
+        }
+    }
+
+    #[test]
+    fn renders_elements_and_components() {
+        let rendered = ServerRenderer::<Card>::with_props(CardProps {
+            title: "Cards & \"Things\"".to_string(),
+            children: Children::new(vec![html! {
+                <>
+                    <input type="checkbox" checked=true value="<on>" />
+                    <textarea value="a < b" />
+                    <br />
+                    { "1 < 2" }
+                </>
+            }]),
+        })
+        .render();
+
+        assert_eq!(
+            rendered,
+            "<section>\
+                <h1 title=\"Cards &amp; &quot;Things&quot;\">Cards &amp; \"Things\"</h1>\
+                <input type=\"checkbox\" value=\"&lt;on&gt;\" checked>\
+                <textarea>a &lt; b</textarea>\
+                <br>\
+                1 &lt; 2\
+            </section>"
+        );
+    }
+
+    #[test]
+    fn renders_function_components_with_context() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Theme(&'static str);
+
+        #[crate::function_component(Consumer)]
+        fn consumer() -> Html {
+            let theme = use_context::<Theme>().expect("no theme");
+            let counter = use_state(|| 0);
+            // Updates during rendering must not panic or affect the output
+            counter.set(*counter + 1);
+
+            html! { <p class={theme.0}>{ *counter }</p> }
+        }
+
+        #[crate::function_component(App)]
+        fn app() -> Html {
+            html! {
+                <ContextProvider<Theme> context={Theme("dark")}>
+                    <Consumer />
+                    <Consumer />
+                </ContextProvider<Theme>>
+            }
+        }
+
+        assert_eq!(
+            ServerRenderer::<App>::new().render(),
+            r#"<p class="dark">0</p><p class="dark">0</p>"#
+        );
+    }
+
+    #[test]
+    fn renders_raw_html_verbatim() {
+        #[crate::function_component(Raw)]
+        fn raw() -> Html {
+            html! {
+                <div>
+                    { Html::from_html_unchecked("<b>bold</b> & <i>italic</i>".into()) }
+                </div>
+            }
+        }
+
+        assert_eq!(
+            ServerRenderer::<Raw>::new().render(),
+            "<div><b>bold</b> & <i>italic</i></div>"
+        );
+    }
+
+    #[test]
+    fn renders_fallback_of_suspended_components() {
+        use crate::suspense::{use_future, Suspense};
+        use crate::HtmlResult;
+
+        #[crate::function_component(Content)]
+        fn content() -> HtmlResult {
+            let text = use_future(|| async { "Content" })?;
+
+            Ok(html! { <p>{ *text }</p> })
+        }
+
+        #[crate::function_component(App)]
+        fn app() -> Html {
+            let fallback = html! { <p>{ "Loading..." }</p> };
+
+            html! {
+                <div>
+                    <Suspense {fallback}>
+                        <span>{ "Before" }</span>
+                        <Content />
+                    </Suspense>
+                </div>
+            }
+        }
+
+        assert_eq!(
+            ServerRenderer::<App>::new().render(),
+            "<div><!--yew-suspended--><p>Loading...</p></div>"
+        );
+    }
+
+    #[test]
+    fn renders_pending_state_of_futures() {
+        use crate::functional::{use_future_with_deps, UseFutureState};
+
+        #[crate::function_component(App)]
+        fn app() -> Html {
+            let state = use_future_with_deps(|_| async { Ok::<_, ()>("Ready") }, ());
+
+            match &*state {
+                UseFutureState::Pending => html! { <p>{ "Pending" }</p> },
+                _ => html! { <p>{ "Done" }</p> },
+            }
+        }
+
+        assert_eq!(ServerRenderer::<App>::new().render(), "<p>Pending</p>");
+    }
+
+    #[cfg(feature = "wasm_test")]
+    #[test]
+    fn renders_node_references_as_nothing() {
+        #[crate::function_component(Ref)]
+        fn node_ref() -> Html {
+            let node = crate::utils::document().create_element("span").unwrap();
+            html! { <div>{ Html::VRef(node.into()) }</div> }
+        }
+
+        assert_eq!(ServerRenderer::<Ref>::new().render(), "<div></div>");
+    }
+}
@@ -0,0 +1,90 @@
+use super::Suspension;
+use crate::virtual_dom::{VNode, VSuspense};
+use crate::{Children, Component, ComponentLink, Html, Properties};
+
+/// Props for [`Suspense`]
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct SuspenseProps {
+    /// Children
+    #[prop_or_default]
+    pub children: Children,
+    /// Rendered instead of the children while any of them is suspended
+    #[prop_or_default]
+    pub fallback: Html,
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub enum SuspenseMsg {
+    Suspend(Suspension),
+    Resume(Suspension),
+    /// The fallback rendered by the server was hydrated, so the suspense has to be rendered
+    /// again to show the children unless they suspended on the client as well.
+    Hydrated,
+}
+
+/// The suspense component.
+///
+/// Renders the `fallback` while any of its children (direct or indirect) is suspended. The
+/// children stay mounted in the meantime, so their state is preserved, and they are shown again as
+/// soon as every [`Suspension`] has been resumed.
+#[derive(Debug)]
+pub struct Suspense {
+    link: ComponentLink<Self>,
+    props: SuspenseProps,
+    suspensions: Vec<Suspension>,
+}
+
+impl Component for Suspense {
+    type Message = SuspenseMsg;
+    type Properties = SuspenseProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            suspensions: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> bool {
+        match msg {
+            SuspenseMsg::Suspend(suspension) => {
+                if suspension.resumed() || self.suspensions.contains(&suspension) {
+                    return false;
+                }
+
+                suspension.listen(self.link.callback(SuspenseMsg::Resume));
+                self.suspensions.push(suspension);
+
+                // Only the first suspension changes what is rendered
+                self.suspensions.len() == 1
+            }
+            SuspenseMsg::Resume(suspension) => {
+                let len = self.suspensions.len();
+                self.suspensions.retain(|m| m != &suspension);
+
+                len != self.suspensions.len() && self.suspensions.is_empty()
+            }
+            SuspenseMsg::Hydrated => self.suspensions.is_empty(),
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> bool {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        VNode::VSuspense(VSuspense::new(
+            self.props.children.iter().collect(),
+            self.props.fallback.clone(),
+            !self.suspensions.is_empty(),
+            None,
+        ))
+    }
+}
@@ -0,0 +1,101 @@
+use super::{Suspension, SuspensionResult};
+use crate::functional::use_hook;
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+use std::ops::Deref;
+use std::rc::Rc;
+
+struct UseFuture<O> {
+    output: Rc<RefCell<Option<Rc<O>>>>,
+    suspension: Suspension,
+}
+
+/// This hook is used to await a future in a function component.
+///
+/// The future is created by `init` on the first render. Until it resolves, the hook returns a
+/// [`Suspension`], so the nearest [`Suspense`](super::Suspense) renders its fallback. Once it
+/// resolves, the component is rendered again and the hook returns a handle to the output.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use yew::suspense::{use_future, Suspense};
+/// #
+/// # async fn fetch_user() -> String {
+/// #     "Yew".to_string()
+/// # }
+/// #
+/// #[function_component(User)]
+/// fn user() -> HtmlResult {
+///     let name = use_future(|| fetch_user())?;
+///
+///     Ok(html! { <p>{ format!("Hello, {}!", *name) }</p> })
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let fallback = html! { <p>{ "Loading..." }</p> };
+///
+///     html! {
+///         <Suspense {fallback}>
+///             <User />
+///         </Suspense>
+///     }
+/// }
+/// ```
+pub fn use_future<F, T, O>(init: F) -> SuspensionResult<UseFutureHandle<O>>
+where
+    F: FnOnce() -> T,
+    T: Future<Output = O> + 'static,
+    O: 'static,
+{
+    use_hook(
+        move || {
+            let output = Rc::new(RefCell::new(None));
+            let suspension = {
+                let output = output.clone();
+                let future = init();
+                Suspension::from_future(async move {
+                    *output.borrow_mut() = Some(Rc::new(future.await));
+                })
+            };
+
+            UseFuture { output, suspension }
+        },
+        |state: &mut UseFuture<O>, _updater| match state.output.borrow().clone() {
+            Some(inner) => Ok(UseFutureHandle { inner }),
+            None => Err(state.suspension.clone()),
+        },
+        |_| {},
+    )
+}
+
+/// Handle to the output of a future awaited by [`use_future`].
+pub struct UseFutureHandle<O> {
+    inner: Rc<O>,
+}
+
+impl<O> Deref for UseFutureHandle<O> {
+    type Target = O;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<O> Clone for UseFutureHandle<O> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<O: fmt::Debug> fmt::Debug for UseFutureHandle<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseFutureHandle")
+            .field("value", &format!("{:?}", self.inner))
+            .finish()
+    }
+}
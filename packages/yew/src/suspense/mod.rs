@@ -0,0 +1,16 @@
+//! This module provides suspense support.
+//!
+//! A function component which returns an [`HtmlResult`](crate::html::HtmlResult) can suspend
+//! rendering until the data it needs is available by returning a [`Suspension`], usually with the
+//! `?` operator on the result of a suspending hook like [`use_future`]. The nearest [`Suspense`]
+//! component then renders its fallback until the suspension is resumed, after which the suspended
+//! component is rendered again.
+
+mod component;
+mod hooks;
+mod suspension;
+
+pub use component::*;
+pub use hooks::*;
+pub(crate) use suspension::{mark_rendering_suspended, render_suspended};
+pub use suspension::{Suspension, SuspensionHandle, SuspensionResult};
@@ -0,0 +1,162 @@
+use crate::Callback;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::future::Future;
+use std::rc::Rc;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_futures::spawn_local;
+
+thread_local! {
+    /// Whether a component was suspended while rendering the children of each `Suspense` which is
+    /// being rendered to a string, innermost last.
+    static RENDERING_SUSPENDED: RefCell<Vec<bool>> = RefCell::default();
+}
+
+/// Calls `render`, which renders the children of a `Suspense` to a string, and returns whether
+/// any of them was suspended.
+pub(crate) fn render_suspended(render: impl FnOnce()) -> bool {
+    RENDERING_SUSPENDED.with(|s| s.borrow_mut().push(false));
+    render();
+    RENDERING_SUSPENDED.with(|s| s.borrow_mut().pop().unwrap_or_default())
+}
+
+/// Marks the innermost `Suspense` which is being rendered to a string as suspended.
+pub(crate) fn mark_rendering_suspended() {
+    RENDERING_SUSPENDED.with(|s| {
+        if let Some(suspended) = s.borrow_mut().last_mut() {
+            *suspended = true;
+        }
+    });
+}
+
+/// A Suspension.
+///
+/// A suspension is returned by a suspending hook or function component while the data it needs is
+/// not available yet. The nearest [`Suspense`](super::Suspense) renders its fallback until the
+/// suspension is resumed.
+#[derive(Clone)]
+pub struct Suspension {
+    resumed: Rc<Cell<bool>>,
+    listeners: Rc<RefCell<Vec<Callback<Self>>>>,
+}
+
+impl fmt::Debug for Suspension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Suspension")
+            .field("resumed", &self.resumed.get())
+            .finish()
+    }
+}
+
+impl fmt::Display for Suspension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("suspended")
+    }
+}
+
+impl std::error::Error for Suspension {}
+
+impl PartialEq for Suspension {
+    fn eq(&self, rhs: &Self) -> bool {
+        Rc::ptr_eq(&self.resumed, &rhs.resumed)
+    }
+}
+
+impl Suspension {
+    /// Creates a Suspension that resumes when the returned [`SuspensionHandle`] is resumed or
+    /// dropped.
+    pub fn new() -> (Self, SuspensionHandle) {
+        let self_ = Suspension {
+            resumed: Rc::default(),
+            listeners: Rc::default(),
+        };
+
+        (self_.clone(), SuspensionHandle { inner: self_ })
+    }
+
+    /// Creates a Suspension that resumes when the future resolves.
+    ///
+    /// The future only runs on `wasm32` targets. Elsewhere, for example when rendering with the
+    /// [`ServerRenderer`](crate::ServerRenderer), there is no executor to run it, so the suspension
+    /// is never resumed and the fallback of the [`Suspense`](super::Suspense) is rendered.
+    ///
+    /// # Panics
+    /// If the future panics, then the suspension is never resumed.
+    pub fn from_future(f: impl Future<Output = ()> + 'static) -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let (self_, handle) = Self::new();
+
+            spawn_local(async move {
+                f.await;
+                handle.resume();
+            });
+
+            self_
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            drop(f);
+            Suspension {
+                resumed: Rc::default(),
+                listeners: Rc::default(),
+            }
+        }
+    }
+
+    /// Returns `true` if the suspension has been resumed.
+    pub fn resumed(&self) -> bool {
+        self.resumed.get()
+    }
+
+    /// Listens to the suspension and gets notified when it resumes.
+    ///
+    /// If the suspension has already been resumed, the callback is notified immediately.
+    pub fn listen(&self, cb: Callback<Self>) {
+        if self.resumed() {
+            cb.emit(self.clone());
+            return;
+        }
+
+        self.listeners.borrow_mut().push(cb);
+    }
+
+    fn resume_by_ref(&self) {
+        // The listeners are taken before they are notified, so that they can listen again.
+        if !self.resumed.replace(true) {
+            let listeners = std::mem::take(&mut *self.listeners.borrow_mut());
+
+            for listener in listeners {
+                listener.emit(self.clone());
+            }
+        }
+    }
+}
+
+/// A [`Suspension`] result.
+///
+/// Suspending hooks return this type, so that the suspension can be propagated to the function
+/// component with the `?` operator.
+pub type SuspensionResult<T> = Result<T, Suspension>;
+
+/// A handle to resume a [`Suspension`].
+///
+/// The suspension is resumed when the handle is dropped.
+#[derive(Debug, PartialEq)]
+pub struct SuspensionHandle {
+    inner: Suspension,
+}
+
+impl SuspensionHandle {
+    /// Resumes the suspension.
+    pub fn resume(self) {
+        self.inner.resume_by_ref();
+    }
+}
+
+impl Drop for SuspensionHandle {
+    fn drop(&mut self) {
+        self.inner.resume_by_ref();
+    }
+}
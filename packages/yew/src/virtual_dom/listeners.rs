@@ -0,0 +1,394 @@
+//! This module contains the delegated event system used to dispatch events to the listeners of
+//! [VTag](super::VTag)s.
+//!
+//! Instead of attaching an event listener to every element, a single listener per event type is
+//! registered on each root, the element an application or the content of a portal is mounted in.
+//! It dispatches events to the capturing listeners from the outermost ancestor of the target
+//! element below the root down to the target, and then to the other listeners from the target up
+//! to the outermost ancestor, until the event does not bubble or propagation was stopped.
+//!
+//! The listener on the root runs in the bubble phase, so native listeners of the elements between
+//! the target and the root have already been called, like they would be without delegation. Only
+//! events which do not bubble, like `focus`, are listened to in the capture phase, because they
+//! would not reach the root otherwise.
+//!
+//! Passive listeners are dispatched to by a separate passive listener on each root.
+//!
+//! Listeners which are not [delegated](Listener::delegated), like the ones of custom events, get
+//! an event listener on their element instead.
+
+use super::{Apply, Listener};
+use crate::html::ListenerOptions;
+use gloo::events::{EventListener, EventListenerOptions, EventListenerPhase};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, Event, Node};
+
+/// The property storing the id of an element's listeners in the registry.
+const LISTENER_ID_PROP: &str = "__yew_listener_id";
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::default();
+}
+
+/// A list of event listeners, either registered or pending registration
+/// TODO(#943): Compare references of handler to do listeners update better
+#[derive(Debug)]
+pub(crate) enum Listeners {
+    /// Listeners pending registration
+    Pending(Vec<Rc<dyn Listener>>),
+
+    /// Already registered listeners.
+    /// Keeps the id of the listeners in the registry to have an opportunity to remove them later
+    Registered(u32),
+}
+
+impl Listeners {
+    /// Removes the registered listeners from the registry, so events are no longer dispatched to
+    /// them.
+    pub(crate) fn unregister(&mut self) {
+        if let Self::Registered(id) = self {
+            Registry::with(|r| r.remove(*id));
+            *self = Self::Pending(vec![]);
+        }
+    }
+}
+
+impl Apply for Listeners {
+    type Element = Element;
+
+    fn apply(&mut self, el: &Self::Element) {
+        if let Self::Pending(v) = self {
+            if !v.is_empty() {
+                *self = Self::Registered(Registry::register(el, std::mem::take(v)));
+            }
+        }
+    }
+
+    fn apply_diff(&mut self, el: &Self::Element, mut ancestor: Self) {
+        match (&mut *self, &ancestor) {
+            (Self::Pending(v), Self::Registered(id)) if !v.is_empty() => {
+                // Keep the id of the ancestor, which is already set on the element
+                let id = *id;
+                Registry::with(|r| r.set(el, id, std::mem::take(v)));
+                *self = Self::Registered(id);
+            }
+            _ => {
+                ancestor.unregister();
+                self.apply(el);
+            }
+        }
+    }
+}
+
+impl PartialEq for Listeners {
+    fn eq(&self, other: &Self) -> bool {
+        use Listeners::*;
+
+        match (self, other) {
+            (Pending(s), Pending(o)) => {
+                s.len() == o.len()
+                    && s.iter()
+                        .map(|l| (l.kind(), l.options()))
+                        .eq(o.iter().map(|l| (l.kind(), l.options())))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Clone for Listeners {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Pending(v) => Self::Pending(v.clone()),
+            Self::Registered(_) => Self::Pending(vec![]),
+        }
+    }
+}
+
+impl From<Vec<Rc<dyn Listener>>> for Listeners {
+    fn from(v: Vec<Rc<dyn Listener>>) -> Self {
+        Self::Pending(v)
+    }
+}
+
+/// Registers `root` as an element which contains elements with listeners, so events of its
+/// descendants are dispatched to them.
+///
+/// Every call must be matched by a call of [`unregister_root`] once nothing is mounted in `root`
+/// anymore.
+pub(crate) fn register_root(root: &Element) {
+    Registry::with(|r| r.register_root(root));
+}
+
+/// Undoes a call of [`register_root`]. The event listeners on `root` are removed once every
+/// application and portal mounted in it unregistered it.
+pub(crate) fn unregister_root(root: &Element) {
+    Registry::with(|r| r.unregister_root(root));
+}
+
+/// An element applications or portals are mounted in.
+struct Root {
+    element: Element,
+    /// The number of applications and portals mounted in the element
+    count: usize,
+    /// One event listener per registered event type and passiveness
+    listeners: Vec<EventListener>,
+}
+
+/// The listeners of all rendered elements and the global event listeners dispatching to them.
+#[derive(Default)]
+struct Registry {
+    /// The elements applications and portals are mounted in
+    roots: Vec<Root>,
+    /// The registered event types and passiveness
+    event_types: HashSet<(&'static str, bool)>,
+    /// The delegated listeners of each element, by the id stored on the element
+    by_id: HashMap<u32, Vec<Rc<dyn Listener>>>,
+    /// The event listeners attached to each element for its listeners which are not delegated
+    attached: HashMap<u32, Vec<EventListener>>,
+    /// The id of the next element to register
+    next_id: u32,
+}
+
+impl Registry {
+    fn with<R>(f: impl FnOnce(&mut Registry) -> R) -> R {
+        REGISTRY.with(|r| f(&mut r.borrow_mut()))
+    }
+
+    /// Registers `listeners` of `el` and returns their id.
+    fn register(el: &Element, listeners: Vec<Rc<dyn Listener>>) -> u32 {
+        Registry::with(|r| {
+            let id = r.next_id;
+            r.next_id += 1;
+            r.set(el, id, listeners);
+            js_sys::Reflect::set(el, &JsValue::from_str(LISTENER_ID_PROP), &JsValue::from(id))
+                .expect("failed to set listener id");
+            id
+        })
+    }
+
+    /// Sets the listeners of `el` registered with `id`, replacing the previous ones.
+    fn set(&mut self, el: &Element, id: u32, listeners: Vec<Rc<dyn Listener>>) {
+        let (delegated, attached): (Vec<_>, Vec<_>) =
+            listeners.into_iter().partition(|l| l.delegated());
+        for l in &delegated {
+            self.register_global(l.event_name(), l.options().passive);
+        }
+        self.by_id.insert(id, delegated);
+        self.attached
+            .insert(id, attached.into_iter().map(|l| attach(el, l)).collect());
+    }
+
+    /// Removes the listeners registered with `id`.
+    fn remove(&mut self, id: u32) {
+        self.by_id.remove(&id);
+        self.attached.remove(&id);
+    }
+
+    /// Registers the event listeners for the registered event types on `root`, if it is not a
+    /// root yet.
+    fn register_root(&mut self, root: &Element) {
+        if let Some(root) = self.roots.iter_mut().find(|r| &r.element == root) {
+            root.count += 1;
+            return;
+        }
+
+        let listeners = self
+            .event_types
+            .iter()
+            .map(|&(event_name, passive)| Registry::listen(root, event_name, passive))
+            .collect();
+        self.roots.push(Root {
+            element: root.clone(),
+            count: 1,
+            listeners,
+        });
+    }
+
+    /// Removes the event listeners on `root` if it was unregistered as often as it was
+    /// registered.
+    fn unregister_root(&mut self, root: &Element) {
+        if let Some(index) = self.roots.iter().position(|r| &r.element == root) {
+            let root = &mut self.roots[index];
+            root.count -= 1;
+            if root.count == 0 {
+                self.roots.remove(index);
+            }
+        }
+    }
+
+    /// Registers the event listeners for `event_name` and `passive` on all roots, if there are
+    /// none yet.
+    fn register_global(&mut self, event_name: &'static str, passive: bool) {
+        if !self.event_types.insert((event_name, passive)) {
+            return;
+        }
+
+        for root in &mut self.roots {
+            root.listeners
+                .push(Registry::listen(&root.element, event_name, passive));
+        }
+    }
+
+    /// Creates the event listener on `root` dispatching events of type `event_name` to the
+    /// listeners with the given passiveness.
+    fn listen(root: &Element, event_name: &'static str, passive: bool) -> EventListener {
+        let options = EventListenerOptions {
+            phase: if bubbles(event_name) {
+                EventListenerPhase::Bubble
+            } else {
+                EventListenerPhase::Capture
+            },
+            passive,
+        };
+        let dispatch_root = root.clone();
+        EventListener::new_with_options(root, event_name, options, move |event| {
+            Registry::dispatch(&dispatch_root, event_name, passive, event)
+        })
+    }
+
+    /// Returns the listeners of `el` for `event_name` matching `filter`.
+    fn listeners(
+        el: &Element,
+        event_name: &str,
+        filter: impl Fn(ListenerOptions) -> bool,
+    ) -> Vec<Rc<dyn Listener>> {
+        let id = match listener_id(el) {
+            Some(id) => id,
+            None => return vec![],
+        };
+        Registry::with(|r| {
+            r.by_id
+                .get(&id)
+                .into_iter()
+                .flatten()
+                .filter(|l| l.event_name() == event_name && filter(l.options()))
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Calls the listeners for `event_name` with the given passiveness of the target of `event`
+    /// and its ancestors below `root`, emulating the capture and bubble phases.
+    fn dispatch(root: &Element, event_name: &'static str, passive: bool, event: &Event) {
+        let target = event
+            .target()
+            .and_then(|target| target.dyn_into::<Node>().ok())
+            .and_then(|node| match node.dyn_into::<Element>() {
+                Ok(el) => Some(el),
+                Err(node) => node.parent_element(),
+            });
+        let mut path = Vec::new();
+        for el in std::iter::successors(target, |el: &Element| el.parent_element()) {
+            if &el == root {
+                break;
+            }
+            if Registry::with(|r| r.roots.iter().any(|root| root.element == el)) {
+                // The elements below a nested root are dispatched to by the listener on that root
+                path.clear();
+            }
+            path.push(el);
+        }
+
+        // Collect the listeners of each element before calling them, as they may render and
+        // register other listeners
+        for el in path.iter().rev() {
+            let listeners =
+                Registry::listeners(el, event_name, |o| o.passive == passive && o.capture);
+            if !Registry::handle(el, listeners, event) {
+                return;
+            }
+        }
+
+        for (i, el) in path.iter().enumerate() {
+            if i > 0 && !event.bubbles() {
+                break;
+            }
+            let listeners =
+                Registry::listeners(el, event_name, |o| o.passive == passive && !o.capture);
+            if !Registry::handle(el, listeners, event) {
+                return;
+            }
+        }
+    }
+
+    /// Calls `listeners` of `el` and returns whether the event should propagate further.
+    fn handle(el: &Element, listeners: Vec<Rc<dyn Listener>>, event: &Event) -> bool {
+        if event.cancel_bubble() {
+            return false;
+        }
+        for l in listeners {
+            l.handle(el, event.clone());
+        }
+        !event.cancel_bubble()
+    }
+}
+
+/// Attaches an event listener calling `listener` to `el`.
+fn attach(el: &Element, listener: Rc<dyn Listener>) -> EventListener {
+    let ListenerOptions { passive, capture } = listener.options();
+    let options = EventListenerOptions {
+        phase: if capture {
+            EventListenerPhase::Capture
+        } else {
+            EventListenerPhase::Bubble
+        },
+        passive,
+    };
+    let target = el.clone();
+    EventListener::new_with_options(el, listener.event_name(), options, move |event| {
+        listener.handle(&target, event.clone())
+    })
+}
+
+/// Returns whether events of type `event_name` bubble.
+fn bubbles(event_name: &str) -> bool {
+    !matches!(
+        event_name,
+        "abort"
+            | "blur"
+            | "canplay"
+            | "canplaythrough"
+            | "cancel"
+            | "close"
+            | "durationchange"
+            | "emptied"
+            | "ended"
+            | "error"
+            | "focus"
+            | "invalid"
+            | "load"
+            | "loadeddata"
+            | "loadedmetadata"
+            | "loadend"
+            | "loadstart"
+            | "mouseenter"
+            | "mouseleave"
+            | "pause"
+            | "play"
+            | "playing"
+            | "pointerenter"
+            | "pointerleave"
+            | "progress"
+            | "ratechange"
+            | "scroll"
+            | "seeked"
+            | "seeking"
+            | "stalled"
+            | "suspend"
+            | "timeupdate"
+            | "toggle"
+            | "volumechange"
+            | "waiting"
+    )
+}
+
+fn listener_id(el: &Element) -> Option<u32> {
+    js_sys::Reflect::get(el, &JsValue::from_str(LISTENER_ID_PROP))
+        .ok()
+        .and_then(|id| id.as_f64())
+        .map(|id| id as u32)
+}
@@ -2,6 +2,7 @@
 
 #[doc(hidden)]
 pub mod key;
+mod listeners;
 #[doc(hidden)]
 pub mod vcomp;
 #[doc(hidden)]
@@ -9,18 +10,24 @@ pub mod vlist;
 #[doc(hidden)]
 pub mod vnode;
 #[doc(hidden)]
+pub mod vportal;
+#[doc(hidden)]
+pub mod vraw;
+#[doc(hidden)]
+pub mod vsuspense;
+#[doc(hidden)]
 pub mod vtag;
 #[doc(hidden)]
 pub mod vtext;
 
-use crate::html::{AnyScope, NodeRef};
-use gloo::events::EventListener;
+use crate::html::{AnyScope, ListenerOptions, NodeRef};
 use indexmap::IndexMap;
 use std::{borrow::Cow, collections::HashMap, fmt, hint::unreachable_unchecked, iter};
 use web_sys::{Element, Node};
 
 #[doc(inline)]
 pub use self::key::Key;
+
 #[doc(inline)]
 pub use self::vcomp::{VChild, VComp};
 #[doc(inline)]
@@ -28,17 +35,38 @@ pub use self::vlist::VList;
 #[doc(inline)]
 pub use self::vnode::VNode;
 #[doc(inline)]
+pub use self::vportal::VPortal;
+#[doc(inline)]
+pub use self::vraw::VRaw;
+#[doc(inline)]
+pub use self::vsuspense::VSuspense;
+#[doc(inline)]
 pub use self::vtag::VTag;
 #[doc(inline)]
 pub use self::vtext::VText;
 
+pub(crate) use self::listeners::{register_root, unregister_root};
+
 /// The `Listener` trait is an universal implementation of an event listener
 /// which is used to bind Rust-listener to JS-listener (DOM).
+///
+/// Listeners are not attached to their elements. Events are dispatched to them by a single
+/// listener per event type on the element the application is mounted in.
 pub trait Listener {
-    /// Returns the name of the event
+    /// Returns the name of the listener, e.g. `onclick`
     fn kind(&self) -> &'static str;
-    /// Attaches a listener to the element.
-    fn attach(&self, element: &Element) -> EventListener;
+    /// Returns the name of the DOM event, e.g. `click`
+    fn event_name(&self) -> &'static str;
+    /// Returns the options of the listener
+    fn options(&self) -> ListenerOptions;
+    /// Handles an event dispatched to the element the listener was added to.
+    fn handle(&self, element: &Element, event: web_sys::Event);
+    /// Returns whether events are dispatched to the listener by the event listener on the element
+    /// the application is mounted in. Otherwise, an event listener is attached to the element of
+    /// the listener.
+    fn delegated(&self) -> bool {
+        true
+    }
 }
 
 impl fmt::Debug for dyn Listener {
@@ -383,6 +411,29 @@ pub(crate) trait VDiff {
         next_sibling: NodeRef,
         ancestor: Option<VNode>,
     ) -> NodeRef;
+
+    /// Adopts existing DOM nodes, usually produced by server-side rendering, instead of creating
+    /// new ones.
+    ///
+    /// Parameters:
+    /// - `parent_scope`: the parent `Scope` used for passing messages to the
+    ///   parent `Component`.
+    /// - `parent`: the parent node in the DOM.
+    /// - `fragment`: the next DOM node to adopt. It is advanced past all adopted nodes.
+    /// - `next_sibling`: the next sibling, used to position nodes inserted by later renders. It
+    ///   may not be resolved yet, as siblings are hydrated from left to right.
+    ///
+    /// Nodes which do not match the virtual node are left in place and new nodes are inserted
+    /// before them. Mismatches are logged in debug builds.
+    ///
+    /// Returns a reference to the first adopted or inserted node.
+    fn hydrate(
+        &mut self,
+        parent_scope: &AnyScope,
+        parent: &Element,
+        fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+    ) -> NodeRef;
 }
 
 pub(crate) fn insert_node(node: &Node, parent: &Element, next_sibling: Option<&Node>) {
@@ -394,6 +445,86 @@ pub(crate) fn insert_node(node: &Node, parent: &Element, next_sibling: Option<&N
     };
 }
 
+/// Logs a mismatch between the existing DOM and the virtual DOM found during hydration.
+/// Only enabled in debug builds.
+pub(crate) fn report_hydration_mismatch(expected: impl fmt::Display, found: Option<&Node>) {
+    #[cfg(debug_assertions)]
+    log::warn!(
+        "hydration mismatch: expected {}, found {}",
+        expected,
+        found.map_or_else(|| "nothing".to_string(), crate::utils::print_node)
+    );
+    #[cfg(not(debug_assertions))]
+    let _ = (expected, found);
+}
+
+/// Removes `fragment` and all of its following siblings from `parent`, as they were not adopted
+/// by any virtual node during hydration.
+pub(crate) fn remove_unhydrated(parent: &Element, mut fragment: Option<Node>) {
+    while let Some(node) = fragment {
+        fragment = node.next_sibling();
+        report_hydration_mismatch("no more nodes", Some(&node));
+        if parent.remove_child(&node).is_err() {
+            log::warn!("Node not found to remove");
+        }
+    }
+}
+
+/// Writes `text` to `w`, escaping characters which would otherwise be interpreted as markup inside
+/// an element.
+pub(crate) fn escape_html(w: &mut String, text: &str) {
+    escape(w, text, false)
+}
+
+/// Writes `value` to `w`, escaping characters which would otherwise terminate a double-quoted
+/// attribute value.
+pub(crate) fn escape_attribute(w: &mut String, value: &str) {
+    escape(w, value, true)
+}
+
+fn escape(w: &mut String, s: &str, quotes: bool) {
+    let mut last = 0;
+    for (i, c) in s.char_indices() {
+        let escaped = match c {
+            '&' => "&amp;",
+            '<' => "&lt;",
+            '>' => "&gt;",
+            '"' if quotes => "&quot;",
+            '\'' if quotes => "&#39;",
+            _ => continue,
+        };
+        w.push_str(&s[last..i]);
+        w.push_str(escaped);
+        last = i + 1;
+    }
+    w.push_str(&s[last..]);
+}
+
+#[cfg(test)]
+mod escape_tests {
+    use super::*;
+
+    #[cfg(feature = "wasm_test")]
+    use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+    #[cfg(feature = "wasm_test")]
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn escapes_text() {
+        let mut w = String::new();
+        escape_html(&mut w, r#"<a href="x">Tom & 'Jerry'</a>"#);
+        assert_eq!(w, r#"&lt;a href="x"&gt;Tom &amp; 'Jerry'&lt;/a&gt;"#);
+    }
+
+    #[test]
+    fn escapes_attributes() {
+        let mut w = String::new();
+        escape_attribute(&mut w, r#"" onclick="alert('&')"#);
+        assert_eq!(w, "&quot; onclick=&quot;alert(&#39;&amp;&#39;)");
+    }
+}
+
 #[cfg(test)]
 mod layout_tests {
     use super::*;
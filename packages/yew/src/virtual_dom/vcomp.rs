@@ -6,7 +6,7 @@ use std::any::TypeId;
 use std::borrow::Borrow;
 use std::fmt;
 use std::ops::Deref;
-use web_sys::Element;
+use web_sys::{Element, Node};
 
 /// A virtual component.
 pub struct VComp {
@@ -102,6 +102,23 @@ impl VComp {
     pub(crate) fn root_vnode(&self) -> Option<impl Deref<Target = VNode> + '_> {
         self.scope.as_ref().and_then(|scope| scope.root_vnode())
     }
+
+    /// Moves the DOM nodes of the component into `next_parent` and renders the component into it
+    /// from now on.
+    pub(crate) fn shift(&self, next_parent: &Element, next_sibling: NodeRef) {
+        self.scope
+            .as_ref()
+            .expect("VComp is not mounted")
+            .shift_node(next_parent.clone(), next_sibling);
+    }
+
+    /// Renders the component and writes its HTML to `w` without mounting it.
+    pub(crate) fn render_to_string(&self, w: &mut String, parent_scope: &AnyScope) {
+        self.props
+            .as_ref()
+            .expect("VComp has already been mounted")
+            .render_to_string(w, parent_scope);
+    }
 }
 
 trait Mountable {
@@ -114,6 +131,15 @@ trait Mountable {
         next_sibling: NodeRef,
     ) -> Box<dyn Scoped>;
     fn reuse(self: Box<Self>, node_ref: NodeRef, scope: &dyn Scoped, next_sibling: NodeRef);
+    fn hydrate(
+        self: Box<Self>,
+        node_ref: NodeRef,
+        parent_scope: &AnyScope,
+        parent: Element,
+        fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+    ) -> Box<dyn Scoped>;
+    fn render_to_string(&self, w: &mut String, parent_scope: &AnyScope);
 }
 
 struct PropsWrapper<COMP: Component> {
@@ -151,6 +177,25 @@ impl<COMP: Component> Mountable for PropsWrapper<COMP> {
         let scope: Scope<COMP> = scope.to_any().downcast();
         scope.reuse(self.props, node_ref, next_sibling);
     }
+
+    fn hydrate(
+        self: Box<Self>,
+        node_ref: NodeRef,
+        parent_scope: &AnyScope,
+        parent: Element,
+        fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+    ) -> Box<dyn Scoped> {
+        let scope: Scope<COMP> = Scope::new(Some(parent_scope.clone()));
+        scope.hydrate_in_place(parent, fragment, next_sibling, node_ref, self.props);
+
+        Box::new(scope)
+    }
+
+    fn render_to_string(&self, w: &mut String, parent_scope: &AnyScope) {
+        let scope: Scope<COMP> = Scope::new(Some(parent_scope.clone()));
+        scope.render_to_string(w, self.props.clone());
+    }
 }
 
 impl VDiff for VComp {
@@ -191,6 +236,25 @@ impl VDiff for VComp {
 
         self.node_ref.clone()
     }
+
+    fn hydrate(
+        &mut self,
+        parent_scope: &AnyScope,
+        parent: &Element,
+        fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+    ) -> NodeRef {
+        let mountable = self.props.take().expect("VComp has already been mounted");
+        self.scope = Some(mountable.hydrate(
+            self.node_ref.clone(),
+            parent_scope,
+            parent.to_owned(),
+            fragment,
+            next_sibling,
+        ));
+
+        self.node_ref.clone()
+    }
 }
 
 impl PartialEq for VComp {
@@ -331,7 +395,7 @@ mod tests {
         let test_node: Node = document().create_text_node("test").into();
         let test_node_ref = NodeRef::new(test_node);
         let check_node_ref = |vnode: VNode| {
-            assert_eq!(vnode.first_node(), test_node_ref.get().unwrap());
+            assert_eq!(vnode.first_node().unwrap(), test_node_ref.get().unwrap());
         };
 
         let props = Props {
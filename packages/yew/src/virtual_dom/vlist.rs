@@ -3,7 +3,7 @@ use super::{Key, VDiff, VNode, VText};
 use crate::html::{AnyScope, NodeRef};
 use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
-use web_sys::Element;
+use web_sys::{Element, Node};
 
 /// This struct represents a fragment of the Virtual DOM tree.
 #[derive(Clone, Debug, PartialEq)]
@@ -107,6 +107,13 @@ impl VList {
         self.fully_keyed = self.children.iter().all(|ch| ch.has_key());
     }
 
+    /// Writes the HTML of all children to `w`.
+    pub(crate) fn render_to_string(&self, w: &mut String, parent_scope: &AnyScope) {
+        for child in self.children.iter() {
+            child.render_to_string(w, parent_scope);
+        }
+    }
+
     /// Diff and patch unkeyed child lists
     fn apply_unkeyed(
         parent_scope: &AnyScope,
@@ -363,6 +370,38 @@ impl VDiff for VList {
         test_log!("result: {:?}", lefts);
         first
     }
+
+    fn hydrate(
+        &mut self,
+        parent_scope: &AnyScope,
+        parent: &Element,
+        fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+    ) -> NodeRef {
+        if self.children.is_empty() {
+            // Stake out a place for later renders, same as in `apply`
+            self.add_child(VText::new("").into());
+        }
+
+        // Children are hydrated from left to right, so the next sibling of each child is linked
+        // once the following child has been hydrated.
+        let mut first = None;
+        let mut prev_next_sibling: Option<NodeRef> = None;
+        for child in self.children.iter_mut() {
+            let child_next_sibling = NodeRef::default();
+            let node_ref =
+                child.hydrate(parent_scope, parent, fragment, child_next_sibling.clone());
+            if let Some(prev_next_sibling) = prev_next_sibling.replace(child_next_sibling) {
+                prev_next_sibling.link(node_ref.clone());
+            }
+            first.get_or_insert(node_ref);
+        }
+        if let Some(prev_next_sibling) = prev_next_sibling {
+            prev_next_sibling.link(next_sibling);
+        }
+
+        first.unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 //! This module contains the implementation of abstract virtual node.
 
-use super::{Key, VChild, VComp, VDiff, VList, VTag, VText};
+use super::{AttrValue, Key, VChild, VComp, VDiff, VList, VPortal, VRaw, VSuspense, VTag, VText};
 use crate::html::{AnyScope, Component, NodeRef};
 use log::warn;
 use std::cmp::PartialEq;
@@ -22,9 +22,23 @@ pub enum VNode {
     VList(VList),
     /// A holder for any `Node` (necessary for replacing node).
     VRef(Node),
+    /// A portal to another part of the document.
+    VPortal(VPortal),
+    /// A suspendable document fragment.
+    VSuspense(VSuspense),
+    /// A raw HTML string, represented by [`AttrValue`](crate::virtual_dom::AttrValue).
+    VRaw(VRaw),
 }
 
 impl VNode {
+    /// Creates a [`VNode`] which inserts the DOM nodes parsed from `html`.
+    ///
+    /// The markup is not escaped or sanitized, so it must be trusted. It is parsed again only when
+    /// the string changes, and it is written verbatim by the [`ServerRenderer`](crate::ServerRenderer).
+    pub fn from_html_unchecked(html: AttrValue) -> Self {
+        VNode::VRaw(VRaw::new(html))
+    }
+
     pub fn key(&self) -> Option<Key> {
         match self {
             VNode::VComp(vcomp) => vcomp.key.clone(),
@@ -32,6 +46,8 @@ impl VNode {
             VNode::VRef(_) => None,
             VNode::VTag(vtag) => vtag.key.clone(),
             VNode::VText(_) => None,
+            VNode::VPortal(_) | VNode::VRaw(_) => None,
+            VNode::VSuspense(vsuspense) => vsuspense.key.clone(),
         }
     }
 
@@ -40,26 +56,75 @@ impl VNode {
         match self {
             VNode::VComp(vcomp) => vcomp.key.is_some(),
             VNode::VList(vlist) => vlist.key.is_some(),
-            VNode::VRef(_) | VNode::VText(_) => false,
+            VNode::VRef(_) | VNode::VText(_) | VNode::VPortal(_) | VNode::VRaw(_) => false,
             VNode::VTag(vtag) => vtag.key.is_some(),
+            VNode::VSuspense(vsuspense) => vsuspense.key.is_some(),
         }
     }
 
     /// Returns the first DOM node that is used to designate the position of the virtual DOM node.
-    pub(crate) fn first_node(&self) -> Node {
+    ///
+    /// A portal has no DOM node in its parent, so the node following it is returned instead, which
+    /// is `None` at the end of the parent.
+    pub(crate) fn first_node(&self) -> Option<Node> {
         match self {
-            VNode::VTag(vtag) => vtag
-                .reference()
-                .expect("VTag is not mounted")
-                .clone()
-                .into(),
+            VNode::VTag(vtag) => Some(
+                vtag.reference()
+                    .expect("VTag is not mounted")
+                    .clone()
+                    .into(),
+            ),
             VNode::VText(vtext) => {
                 let text_node = vtext.reference.as_ref().expect("VText is not mounted");
-                text_node.clone().into()
+                Some(text_node.clone().into())
             }
-            VNode::VComp(vcomp) => vcomp.node_ref.get().expect("VComp is not mounted"),
+            VNode::VComp(vcomp) => Some(vcomp.node_ref.get().expect("VComp is not mounted")),
             VNode::VList(vlist) => vlist.get(0).expect("VList is not mounted").first_node(),
-            VNode::VRef(node) => node.clone(),
+            VNode::VRef(node) => Some(node.clone()),
+            VNode::VPortal(vportal) => vportal.next_sibling_in_parent(),
+            VNode::VSuspense(vsuspense) => vsuspense.first_node(),
+            VNode::VRaw(vraw) => vraw.first_node(),
+        }
+    }
+
+    /// Writes the HTML of this node and its descendants to `w` without touching the DOM.
+    pub(crate) fn render_to_string(&self, w: &mut String, parent_scope: &AnyScope) {
+        match self {
+            VNode::VTag(vtag) => vtag.render_to_string(w, parent_scope),
+            VNode::VText(vtext) => vtext.render_to_string(w),
+            VNode::VComp(vcomp) => vcomp.render_to_string(w, parent_scope),
+            VNode::VList(vlist) => vlist.render_to_string(w, parent_scope),
+            // A DOM node only exists in the browser, so there is nothing to render on a server
+            VNode::VRef(_) => {}
+            // The content of a portal is rendered into its host on the client
+            VNode::VPortal(_) => {}
+            VNode::VSuspense(vsuspense) => vsuspense.render_to_string(w, parent_scope),
+            VNode::VRaw(vraw) => vraw.render_to_string(w),
+        }
+    }
+
+    /// Moves the DOM nodes of this mounted node into `next_parent` before `next_sibling`.
+    ///
+    /// Unlike `move_before`, the components are moved to `next_parent` as well, so they render
+    /// into it from now on.
+    pub(crate) fn shift(&self, next_parent: &Element, next_sibling: NodeRef) {
+        match self {
+            VNode::VList(vlist) => {
+                let mut next_sibling = next_sibling;
+                for node in vlist.iter().rev() {
+                    node.shift(next_parent, next_sibling);
+                    next_sibling = node.first_node().map(NodeRef::new).unwrap_or_default();
+                }
+            }
+            VNode::VComp(vcomp) => vcomp.shift(next_parent, next_sibling),
+            VNode::VPortal(vportal) => vportal.shift(next_sibling),
+            VNode::VRaw(vraw) => vraw.shift(next_parent, next_sibling),
+            VNode::VSuspense(vsuspense) => vsuspense.shift(next_parent, next_sibling),
+            VNode::VTag(_) | VNode::VText(_) | VNode::VRef(_) => {
+                if let Some(node) = self.first_node() {
+                    super::insert_node(&node, next_parent, next_sibling.get().as_ref());
+                }
+            }
         }
     }
 
@@ -76,7 +141,15 @@ impl VNode {
                     .expect("VComp has no root vnode")
                     .move_before(parent, next_sibling);
             }
-            _ => super::insert_node(&self.first_node(), parent, next_sibling.as_ref()),
+            VNode::VSuspense(vsuspense) => vsuspense.move_before(parent, next_sibling),
+            // The content of a portal stays in its host
+            VNode::VPortal(_) => {}
+            VNode::VRaw(vraw) => vraw.move_before(parent, next_sibling),
+            VNode::VTag(_) | VNode::VText(_) | VNode::VRef(_) => {
+                if let Some(node) = self.first_node() {
+                    super::insert_node(&node, parent, next_sibling.as_ref());
+                }
+            }
         };
     }
 }
@@ -94,6 +167,9 @@ impl VDiff for VNode {
                     warn!("Node not found to remove VRef");
                 }
             }
+            VNode::VPortal(ref mut vportal) => vportal.detach(parent),
+            VNode::VSuspense(ref mut vsuspense) => vsuspense.detach(parent),
+            VNode::VRaw(ref mut vraw) => vraw.detach(parent),
         }
     }
 
@@ -127,6 +203,46 @@ impl VDiff for VNode {
                 super::insert_node(node, parent, next_sibling.get().as_ref());
                 NodeRef::new(node.clone())
             }
+            VNode::VPortal(ref mut vportal) => {
+                vportal.apply(parent_scope, parent, next_sibling, ancestor)
+            }
+            VNode::VSuspense(ref mut vsuspense) => {
+                vsuspense.apply(parent_scope, parent, next_sibling, ancestor)
+            }
+            VNode::VRaw(ref mut vraw) => vraw.apply(parent_scope, parent, next_sibling, ancestor),
+        }
+    }
+
+    fn hydrate(
+        &mut self,
+        parent_scope: &AnyScope,
+        parent: &Element,
+        fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+    ) -> NodeRef {
+        match *self {
+            VNode::VTag(ref mut vtag) => vtag.hydrate(parent_scope, parent, fragment, next_sibling),
+            VNode::VText(ref mut vtext) => {
+                vtext.hydrate(parent_scope, parent, fragment, next_sibling)
+            }
+            VNode::VComp(ref mut vcomp) => {
+                vcomp.hydrate(parent_scope, parent, fragment, next_sibling)
+            }
+            VNode::VList(ref mut vlist) => {
+                vlist.hydrate(parent_scope, parent, fragment, next_sibling)
+            }
+            // A `VRef` can not be rendered on the server, so it is always inserted
+            VNode::VRef(ref node) => {
+                super::insert_node(node, parent, fragment.as_ref());
+                NodeRef::new(node.clone())
+            }
+            VNode::VPortal(ref mut vportal) => {
+                vportal.hydrate(parent_scope, parent, fragment, next_sibling)
+            }
+            VNode::VSuspense(ref mut vsuspense) => {
+                vsuspense.hydrate(parent_scope, parent, fragment, next_sibling)
+            }
+            VNode::VRaw(ref mut vraw) => vraw.hydrate(parent_scope, parent, fragment, next_sibling),
         }
     }
 }
@@ -144,6 +260,27 @@ impl From<VText> for VNode {
     }
 }
 
+impl From<VPortal> for VNode {
+    #[inline]
+    fn from(vportal: VPortal) -> Self {
+        VNode::VPortal(vportal)
+    }
+}
+
+impl From<VRaw> for VNode {
+    #[inline]
+    fn from(vraw: VRaw) -> Self {
+        VNode::VRaw(vraw)
+    }
+}
+
+impl From<VSuspense> for VNode {
+    #[inline]
+    fn from(vsuspense: VSuspense) -> Self {
+        VNode::VSuspense(vsuspense)
+    }
+}
+
 impl From<VList> for VNode {
     #[inline]
     fn from(vlist: VList) -> Self {
@@ -197,6 +334,9 @@ impl fmt::Debug for VNode {
             VNode::VComp(ref vcomp) => vcomp.fmt(f),
             VNode::VList(ref vlist) => vlist.fmt(f),
             VNode::VRef(ref vref) => write!(f, "VRef ( \"{}\" )", crate::utils::print_node(vref)),
+            VNode::VPortal(ref vportal) => vportal.fmt(f),
+            VNode::VSuspense(ref vsuspense) => vsuspense.fmt(f),
+            VNode::VRaw(ref vraw) => vraw.fmt(f),
         }
     }
 }
@@ -208,6 +348,9 @@ impl PartialEq for VNode {
             (VNode::VText(a), VNode::VText(b)) => a == b,
             (VNode::VList(a), VNode::VList(b)) => a == b,
             (VNode::VRef(a), VNode::VRef(b)) => a == b,
+            (VNode::VPortal(a), VNode::VPortal(b)) => a == b,
+            (VNode::VSuspense(a), VNode::VSuspense(b)) => a == b,
+            (VNode::VRaw(a), VNode::VRaw(b)) => a == b,
             // TODO: Need to improve PartialEq for VComp before enabling.
             (VNode::VComp(_), VNode::VComp(_)) => false,
             _ => false,
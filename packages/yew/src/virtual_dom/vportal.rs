@@ -0,0 +1,208 @@
+//! This module contains the implementation of a portal `VPortal`.
+
+use super::{VDiff, VNode};
+use crate::html::{AnyScope, NodeRef};
+use web_sys::{Element, Node};
+
+/// A portal renders its content into a host element instead of its logical parent.
+///
+/// The content stays in the component tree at the position of the portal, so contexts and
+/// `NodeRef`s keep working.
+#[derive(Debug, Clone)]
+pub struct VPortal {
+    /// The element under which the content is inserted.
+    pub host: Element,
+    /// The next sibling of the content in the host, or `None` to append it.
+    pub next_sibling: Option<Node>,
+    /// The inserted node.
+    pub node: Box<VNode>,
+    /// The next sibling of the portal in its logical parent.
+    sibling_ref: NodeRef,
+}
+
+impl VPortal {
+    /// Creates a [VPortal] rendering `content` at the end of `host`.
+    pub fn new(content: VNode, host: Element) -> Self {
+        Self::new_before(content, host, None)
+    }
+
+    /// Creates a [VPortal] rendering `content` in `host` before `next_sibling`, or at the end if
+    /// it is `None`.
+    pub fn new_before(content: VNode, host: Element, next_sibling: Option<Node>) -> Self {
+        Self {
+            host,
+            next_sibling,
+            node: Box::new(content),
+            sibling_ref: NodeRef::default(),
+        }
+    }
+
+    /// Returns the node that follows the portal in its logical parent. A portal has no node of
+    /// its own there, so nodes inserted before it are inserted before this node.
+    pub(crate) fn next_sibling_in_parent(&self) -> Option<Node> {
+        self.sibling_ref.get()
+    }
+
+    /// Moving a portal only changes its position in the logical parent, the content stays in the
+    /// host.
+    pub(crate) fn shift(&self, next_sibling: NodeRef) {
+        self.sibling_ref.link(next_sibling);
+    }
+
+    fn content_sibling(&self) -> NodeRef {
+        self.next_sibling
+            .clone()
+            .map(NodeRef::new)
+            .unwrap_or_default()
+    }
+}
+
+impl VDiff for VPortal {
+    fn detach(&mut self, _parent: &Element) {
+        self.node.detach(&self.host);
+        self.sibling_ref.set(None);
+        super::unregister_root(&self.host);
+    }
+
+    fn apply(
+        &mut self,
+        parent_scope: &AnyScope,
+        parent: &Element,
+        next_sibling: NodeRef,
+        ancestor: Option<VNode>,
+    ) -> NodeRef {
+        let content_sibling = self.content_sibling();
+        let ancestor = match ancestor {
+            Some(VNode::VPortal(old)) => {
+                if old.host != self.host {
+                    super::register_root(&self.host);
+                    super::unregister_root(&old.host);
+                }
+                // Keep the content mounted when only the host changes
+                if old.host != self.host || old.next_sibling != self.next_sibling {
+                    old.node.shift(&self.host, content_sibling.clone());
+                }
+                Some(*old.node)
+            }
+            Some(mut node) => {
+                node.detach(parent);
+                super::register_root(&self.host);
+                None
+            }
+            None => {
+                super::register_root(&self.host);
+                None
+            }
+        };
+
+        self.node
+            .apply(parent_scope, &self.host, content_sibling, ancestor);
+        self.sibling_ref.link(next_sibling.clone());
+        next_sibling
+    }
+
+    /// The content of a portal is not rendered on the server, so it is always created.
+    fn hydrate(
+        &mut self,
+        parent_scope: &AnyScope,
+        parent: &Element,
+        _fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+    ) -> NodeRef {
+        self.apply(parent_scope, parent, next_sibling, None)
+    }
+}
+
+impl PartialEq for VPortal {
+    fn eq(&self, other: &Self) -> bool {
+        self.host == other.host
+            && self.next_sibling == other.next_sibling
+            && self.node == other.node
+    }
+}
+
+#[cfg(test)]
+mod layout_tests {
+    extern crate self as yew;
+
+    use super::*;
+    use crate::html;
+    use crate::utils::document;
+    use crate::virtual_dom::layout_tests::{diff_layouts, TestLayout};
+    use crate::virtual_dom::VNode;
+
+    #[cfg(feature = "wasm_test")]
+    use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+    #[cfg(feature = "wasm_test")]
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn diff() {
+        let mut layouts = vec![];
+        let first_target = document().create_element("i").unwrap();
+        let second_target = document().create_element("o").unwrap();
+        let target_with_child = document().create_element("i").unwrap();
+        let target_child = document().create_element("s").unwrap();
+        target_with_child.append_child(&target_child).unwrap();
+
+        layouts.push(TestLayout {
+            name: "Portal - first target",
+            node: html! {
+                <div>
+                    {VNode::VRef(first_target.clone().into())}
+                    {VNode::VRef(second_target.clone().into())}
+                    {VNode::VPortal(VPortal::new(
+                        html! { {"PORTAL"} },
+                        first_target.clone(),
+                    ))}
+                    {"AFTER"}
+                </div>
+            },
+            expected: "<div><i>PORTAL</i><o></o>AFTER</div>",
+        });
+        layouts.push(TestLayout {
+            name: "Portal - second target",
+            node: html! {
+                <div>
+                    {VNode::VRef(first_target.clone().into())}
+                    {VNode::VRef(second_target.clone().into())}
+                    {VNode::VPortal(VPortal::new(
+                        html! { {"PORTAL"} },
+                        second_target.clone(),
+                    ))}
+                    {"AFTER"}
+                </div>
+            },
+            expected: "<div><i></i><o>PORTAL</o>AFTER</div>",
+        });
+        layouts.push(TestLayout {
+            name: "Portal - replaced by text",
+            node: html! {
+                <div>
+                    {VNode::VRef(first_target.clone().into())}
+                    {VNode::VRef(second_target.clone().into())}
+                    {"FOO"}
+                    {"AFTER"}
+                </div>
+            },
+            expected: "<div><i></i><o></o>FOOAFTER</div>",
+        });
+        layouts.push(TestLayout {
+            name: "Portal - next sibling",
+            node: html! {
+                <div>
+                    {VNode::VRef(target_with_child.clone().into())}
+                    {VNode::VPortal(VPortal::new_before(
+                        html! { {"PORTAL"} },
+                        target_with_child.clone(),
+                        Some(target_child.clone().into()),
+                    ))}
+                </div>
+            },
+            expected: "<div><i>PORTAL<s></s></i></div>",
+        });
+
+        diff_layouts(layouts)
+    }
+}
@@ -0,0 +1,228 @@
+//! This module contains the implementation of a virtual raw HTML node `VRaw`.
+
+use super::{AttrValue, VDiff, VNode};
+use crate::html::{AnyScope, NodeRef};
+use crate::utils::document;
+use log::warn;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, Node, Text};
+
+/// A raw HTML string, which is inserted into the DOM without any escaping.
+///
+/// Only use it with trusted markup, as any script in it is able to run.
+#[derive(Clone, Debug)]
+pub struct VRaw {
+    /// The HTML string.
+    pub html: AttrValue,
+    /// The DOM nodes parsed from the HTML.
+    nodes: Vec<Node>,
+    /// The next sibling in the parent, marks the position of markup which has no nodes.
+    sibling_ref: NodeRef,
+}
+
+impl VRaw {
+    /// Creates a new [VRaw] from trusted markup.
+    pub fn new(html: impl Into<AttrValue>) -> Self {
+        Self {
+            html: html.into(),
+            nodes: Vec::new(),
+            sibling_ref: NodeRef::default(),
+        }
+    }
+
+    /// Returns the first DOM node parsed from the HTML, or the node following it if the HTML
+    /// is empty.
+    pub(crate) fn first_node(&self) -> Option<Node> {
+        self.nodes
+            .first()
+            .cloned()
+            .or_else(|| self.sibling_ref.get())
+    }
+
+    pub(crate) fn move_before(&self, parent: &Element, next_sibling: &Option<Node>) {
+        for node in &self.nodes {
+            super::insert_node(node, parent, next_sibling.as_ref());
+        }
+    }
+
+    pub(crate) fn shift(&self, next_parent: &Element, next_sibling: NodeRef) {
+        self.move_before(next_parent, &next_sibling.get());
+        self.sibling_ref.link(next_sibling);
+    }
+
+    /// Writes the HTML string to `w` verbatim.
+    pub(crate) fn render_to_string(&self, w: &mut String) {
+        w.push_str(&self.html);
+    }
+
+    fn node_ref(&self, next_sibling: NodeRef) -> NodeRef {
+        match self.nodes.first() {
+            Some(node) => NodeRef::new(node.clone()),
+            None => next_sibling,
+        }
+    }
+
+    /// Parses the HTML in the context of `parent`, so that for example table rows and SVG
+    /// elements are parsed correctly.
+    fn parse(&self, parent: &Element) -> Vec<Node> {
+        let namespace = parent.namespace_uri();
+        let container = document()
+            .create_element_ns(namespace.as_deref(), &parent.local_name())
+            .expect("failed to create element");
+        container.set_inner_html(&self.html);
+
+        let mut nodes = Vec::new();
+        while let Some(node) = container.first_child() {
+            container
+                .remove_child(&node)
+                .expect("failed to remove parsed node");
+            nodes.push(node);
+        }
+        nodes
+    }
+}
+
+impl VDiff for VRaw {
+    fn detach(&mut self, parent: &Element) {
+        for node in self.nodes.drain(..) {
+            if parent.remove_child(&node).is_err() {
+                warn!("Node not found to remove VRaw");
+            }
+        }
+        self.sibling_ref.set(None);
+    }
+
+    /// The HTML is only parsed again when it changes.
+    fn apply(
+        &mut self,
+        _parent_scope: &AnyScope,
+        parent: &Element,
+        next_sibling: NodeRef,
+        ancestor: Option<VNode>,
+    ) -> NodeRef {
+        if let Some(mut ancestor) = ancestor {
+            if let VNode::VRaw(vraw) = &mut ancestor {
+                if vraw.html == self.html {
+                    self.nodes = std::mem::take(&mut vraw.nodes);
+                    self.sibling_ref.link(next_sibling.clone());
+                    return self.node_ref(next_sibling);
+                }
+            }
+
+            ancestor.detach(parent);
+        }
+
+        self.nodes = self.parse(parent);
+        self.move_before(parent, &next_sibling.get());
+        self.sibling_ref.link(next_sibling.clone());
+        self.node_ref(next_sibling)
+    }
+
+    /// Adopts the nodes at `fragment` which are equal to the ones parsed from the HTML.
+    fn hydrate(
+        &mut self,
+        _parent_scope: &AnyScope,
+        parent: &Element,
+        fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+    ) -> NodeRef {
+        self.nodes = self
+            .parse(parent)
+            .into_iter()
+            .map(|node| {
+                split_merged_text(fragment.as_ref(), &node);
+                match fragment.take() {
+                    Some(existing) if existing.is_equal_node(Some(&node)) => {
+                        *fragment = existing.next_sibling();
+                        existing
+                    }
+                    existing => {
+                        super::report_hydration_mismatch(
+                            format_args!("raw html {:?}", self.html),
+                            existing.as_ref(),
+                        );
+                        super::insert_node(&node, parent, existing.as_ref());
+                        *fragment = existing;
+                        node
+                    }
+                }
+            })
+            .collect();
+        self.sibling_ref.link(next_sibling.clone());
+        self.node_ref(next_sibling)
+    }
+}
+
+/// Adjacent text is merged into a single text node by the HTML parser, so a text node at
+/// `fragment` that starts with the text of `node` is split in two.
+fn split_merged_text(fragment: Option<&Node>, node: &Node) {
+    let existing = fragment.and_then(|fragment| fragment.dyn_ref::<Text>());
+    if let (Some(existing), Some(text)) = (existing, node.dyn_ref::<Text>()) {
+        let content = existing.data();
+        let text = text.data();
+        if content.len() > text.len() && content.starts_with(&text) {
+            existing
+                .split_text(text.encode_utf16().count() as u32)
+                .expect("failed to split text node");
+        }
+    }
+}
+
+impl PartialEq for VRaw {
+    fn eq(&self, other: &Self) -> bool {
+        self.html == other.html
+    }
+}
+
+#[cfg(test)]
+mod layout_tests {
+    extern crate self as yew;
+
+    use super::*;
+    use crate::html;
+    use crate::virtual_dom::layout_tests::{diff_layouts, TestLayout};
+
+    #[cfg(feature = "wasm_test")]
+    use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+    #[cfg(feature = "wasm_test")]
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn diff() {
+        let layout1 = TestLayout {
+            name: "1",
+            node: html! {
+                <>
+                    {VNode::from_html_unchecked("<b>bold</b> text".into())}
+                    {"after"}
+                </>
+            },
+            expected: "<b>bold</b> textafter",
+        };
+
+        let layout2 = TestLayout {
+            name: "2",
+            node: html! {
+                <>
+                    {VNode::from_html_unchecked("<i>italic</i>".into())}
+                    {"after"}
+                </>
+            },
+            expected: "<i>italic</i>after",
+        };
+
+        let layout3 = TestLayout {
+            name: "3",
+            node: html! {
+                <>
+                    {VNode::from_html_unchecked("".into())}
+                    {"after"}
+                </>
+            },
+            expected: "after",
+        };
+
+        diff_layouts(vec![layout1, layout2, layout3]);
+    }
+}
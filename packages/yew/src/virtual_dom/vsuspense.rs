@@ -0,0 +1,211 @@
+//! This module contains the implementation of a suspendable fragment `VSuspense`.
+
+use super::{Key, VDiff, VNode};
+use crate::html::{AnyScope, NodeRef};
+use crate::suspense::{self, Suspense, SuspenseMsg};
+use crate::utils::document;
+use wasm_bindgen::JsCast;
+use web_sys::{Comment, Element, Node};
+
+/// The content of the comment the server writes before the fallback of a suspended
+/// [`VSuspense`], so the client hydrates the fallback instead of the children.
+const SUSPENDED_MARKER: &str = "yew-suspended";
+
+/// This struct represents a suspendable DOM fragment.
+///
+/// While suspended, the children are kept mounted in a detached element and the fallback is
+/// rendered in their place.
+#[derive(Clone, Debug)]
+pub struct VSuspense {
+    /// Child nodes.
+    children: Box<VNode>,
+    /// Fallback nodes when suspended.
+    fallback: Box<VNode>,
+    /// The element the children are mounted in while suspended.
+    detached_parent: Option<Element>,
+    /// Whether the current status is suspended.
+    suspended: bool,
+    /// The Key.
+    pub(crate) key: Option<Key>,
+}
+
+impl VSuspense {
+    pub(crate) fn new(children: VNode, fallback: VNode, suspended: bool, key: Option<Key>) -> Self {
+        Self {
+            children: children.into(),
+            fallback: fallback.into(),
+            detached_parent: None,
+            suspended,
+            key,
+        }
+    }
+
+    /// Returns the node that is currently rendered in the parent element.
+    fn active_node(&self) -> &VNode {
+        if self.suspended {
+            &self.fallback
+        } else {
+            &self.children
+        }
+    }
+
+    pub(crate) fn first_node(&self) -> Option<Node> {
+        self.active_node().first_node()
+    }
+
+    pub(crate) fn move_before(&self, parent: &Element, next_sibling: &Option<Node>) {
+        self.active_node().move_before(parent, next_sibling);
+    }
+
+    pub(crate) fn shift(&self, next_parent: &Element, next_sibling: NodeRef) {
+        self.active_node().shift(next_parent, next_sibling);
+    }
+
+    /// Writes the HTML of the children, or of the fallback if any of them is suspended, to `w`.
+    ///
+    /// The fallback is preceded by a marker comment, so hydration knows which one was rendered.
+    pub(crate) fn render_to_string(&self, w: &mut String, parent_scope: &AnyScope) {
+        let mut children = String::new();
+        if self.suspended
+            || suspense::render_suspended(|| {
+                self.children.render_to_string(&mut children, parent_scope)
+            })
+        {
+            w.push_str("<!--");
+            w.push_str(SUSPENDED_MARKER);
+            w.push_str("-->");
+            self.fallback.render_to_string(w, parent_scope);
+        } else {
+            w.push_str(&children);
+        }
+    }
+
+    fn detached_parent(&mut self, ancestor: Option<&VSuspense>) -> Element {
+        let detached_parent = ancestor
+            .and_then(|m| m.detached_parent.clone())
+            .unwrap_or_else(|| {
+                document()
+                    .create_element("div")
+                    .expect("failed to create detached element")
+            });
+        self.detached_parent = Some(detached_parent.clone());
+        detached_parent
+    }
+}
+
+impl VDiff for VSuspense {
+    fn detach(&mut self, parent: &Element) {
+        if self.suspended {
+            self.fallback.detach(parent);
+            if let Some(detached_parent) = &self.detached_parent {
+                self.children.detach(detached_parent);
+            }
+        } else {
+            self.children.detach(parent);
+        }
+    }
+
+    fn apply(
+        &mut self,
+        parent_scope: &AnyScope,
+        parent: &Element,
+        next_sibling: NodeRef,
+        ancestor: Option<VNode>,
+    ) -> NodeRef {
+        let ancestor = match ancestor {
+            Some(VNode::VSuspense(m)) if m.key == self.key => Some(m),
+            Some(mut m) => {
+                m.detach(parent);
+                None
+            }
+            None => None,
+        };
+        let detached_parent = self.detached_parent(ancestor.as_ref());
+
+        let (was_suspended, children_ancestor, fallback_ancestor) = match ancestor {
+            Some(m) => (m.suspended, Some(*m.children), Some(*m.fallback)),
+            None => (false, None, None),
+        };
+
+        match (was_suspended, self.suspended) {
+            (_, false) => {
+                if was_suspended {
+                    if let Some(mut fallback) = fallback_ancestor {
+                        fallback.detach(parent);
+                    }
+                    if let Some(children) = &children_ancestor {
+                        children.shift(parent, next_sibling.clone());
+                    }
+                }
+
+                self.children
+                    .apply(parent_scope, parent, next_sibling, children_ancestor)
+            }
+            (_, true) => {
+                if !was_suspended {
+                    if let Some(children) = &children_ancestor {
+                        children.shift(&detached_parent, NodeRef::default());
+                    }
+                }
+
+                self.children.apply(
+                    parent_scope,
+                    &detached_parent,
+                    NodeRef::default(),
+                    children_ancestor,
+                );
+                // The fallback of a suspense which has just been suspended is rendered from scratch
+                let fallback_ancestor = fallback_ancestor.filter(|_| was_suspended);
+                self.fallback
+                    .apply(parent_scope, parent, next_sibling, fallback_ancestor)
+            }
+        }
+    }
+
+    fn hydrate(
+        &mut self,
+        parent_scope: &AnyScope,
+        parent: &Element,
+        fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+    ) -> NodeRef {
+        let marker = fragment
+            .clone()
+            .and_then(|node| node.dyn_into::<Comment>().ok())
+            .filter(|comment| comment.data() == SUSPENDED_MARKER);
+        if let Some(marker) = marker {
+            *fragment = marker.next_sibling();
+            marker.remove();
+
+            // The children are rendered from scratch once none of them is suspended anymore.
+            // They may not suspend on the client, so the suspense is rendered again after they
+            // were created.
+            if !self.suspended {
+                self.suspended = true;
+                if let Some(suspense) = parent_scope.find_parent_scope::<Suspense>() {
+                    suspense.send_message(SuspenseMsg::Hydrated);
+                }
+            }
+        }
+
+        if self.suspended {
+            let detached_parent = self.detached_parent(None);
+            self.children
+                .apply(parent_scope, &detached_parent, NodeRef::default(), None);
+            self.fallback
+                .hydrate(parent_scope, parent, fragment, next_sibling)
+        } else {
+            self.children
+                .hydrate(parent_scope, parent, fragment, next_sibling)
+        }
+    }
+}
+
+impl PartialEq for VSuspense {
+    fn eq(&self, other: &Self) -> bool {
+        self.children == other.children
+            && self.fallback == other.fallback
+            && self.suspended == other.suspended
+            && self.key == other.key
+    }
+}
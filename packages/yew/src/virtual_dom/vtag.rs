@@ -1,9 +1,9 @@
 //! This module contains the implementation of a virtual element node [VTag].
 
+use super::listeners::Listeners;
 use super::{Apply, AttrValue, Attributes, Key, Listener, VDiff, VList, VNode};
 use crate::html::{AnyScope, IntoPropValue, NodeRef};
 use crate::utils::document;
-use gloo::events::EventListener;
 use log::warn;
 use std::borrow::Cow;
 use std::cmp::PartialEq;
@@ -14,7 +14,9 @@ use std::ops::Deref;
 use std::rc::Rc;
 use std::sync::Once;
 use wasm_bindgen::JsCast;
-use web_sys::{Element, HtmlInputElement as InputElement, HtmlTextAreaElement as TextAreaElement};
+use web_sys::{
+    Element, HtmlInputElement as InputElement, HtmlTextAreaElement as TextAreaElement, Node,
+};
 
 /// SVG namespace string used for creating svg elements
 pub const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
@@ -145,67 +147,6 @@ enum VTagInner {
     },
 }
 
-/// A list of event listeners, either registered or pending registration
-/// TODO(#943): Compare references of handler to do listeners update better
-#[derive(Debug)]
-enum Listeners {
-    /// Listeners pending registration
-    Pending(Vec<Rc<dyn Listener>>),
-
-    /// Already registered listeners.
-    /// Keeps handlers for attached listeners to have an opportunity to drop them later
-    Registered(Vec<EventListener>),
-}
-
-impl Apply for Listeners {
-    type Element = Element;
-
-    fn apply(&mut self, el: &Self::Element) {
-        if let Self::Pending(v) = self {
-            *self = Self::Registered(
-                std::mem::take(v)
-                    .into_iter()
-                    .map(|l| l.attach(el))
-                    .collect(),
-            );
-        }
-    }
-
-    fn apply_diff(&mut self, el: &Self::Element, _ancestor: Self) {
-        // All we need to do with `_ancestor` is drop it
-
-        self.apply(el);
-    }
-}
-
-impl PartialEq for Listeners {
-    fn eq(&self, other: &Self) -> bool {
-        use Listeners::*;
-
-        match (self, other) {
-            (Pending(s), Pending(o)) => {
-                s.len() == o.len() && s.iter().map(|l| l.kind()).eq(o.iter().map(|l| l.kind()))
-            }
-            _ => false,
-        }
-    }
-}
-
-impl Clone for Listeners {
-    fn clone(&self) -> Self {
-        match self {
-            Self::Pending(v) => Self::Pending(v.clone()),
-            Self::Registered(_) => Self::Registered(vec![]),
-        }
-    }
-}
-
-impl From<Vec<Rc<dyn Listener>>> for Listeners {
-    fn from(v: Vec<Rc<dyn Listener>>) -> Self {
-        Self::Pending(v)
-    }
-}
-
 /// A type for a virtual
 /// [Element](https://developer.mozilla.org/en-US/docs/Web/API/Element)
 /// representation.
@@ -499,7 +440,7 @@ impl VTag {
 
     /// Adds new listener to the node.
     /// It's boxed because we want to keep it in a single list.
-    /// Later `Listener::attach` will attach an actual listener to a DOM node.
+    /// Later it will be registered to receive the events dispatched to the DOM node.
     pub fn add_listener(&mut self, listener: Rc<dyn Listener>) {
         if let Listeners::Pending(v) = &mut self.listeners {
             v.push(listener);
@@ -508,7 +449,7 @@ impl VTag {
 
     /// Adds new listeners to the node.
     /// They are boxed because we want to keep them in a single list.
-    /// Later `Listener::attach` will attach an actual listener to a DOM node.
+    /// Later it will be registered to receive the events dispatched to the DOM node.
     pub fn add_listeners(&mut self, listeners: Vec<Rc<dyn Listener>>) {
         if let Listeners::Pending(v) = &mut self.listeners {
             v.extend(listeners);
@@ -532,8 +473,62 @@ impl VTag {
                 .expect("can't create element for vtag")
         }
     }
+
+    /// Writes the HTML of the element and its children to `w`. Listeners are skipped.
+    pub(crate) fn render_to_string(&self, w: &mut String, parent_scope: &AnyScope) {
+        macro_rules! write_attr {
+            ($key:expr, $value:expr) => {{
+                w.push(' ');
+                w.push_str($key);
+                w.push_str("=\"");
+                super::escape_attribute(w, $value);
+                w.push('"');
+            }};
+        }
+
+        let tag = self.tag();
+        w.push('<');
+        w.push_str(tag);
+        for (key, value) in self.attributes.iter() {
+            write_attr!(key, value);
+        }
+
+        match &self.inner {
+            VTagInner::Input(f) => {
+                if let Some(value) = &f.value.0 {
+                    write_attr!("value", value);
+                }
+                if f.checked {
+                    w.push_str(" checked");
+                }
+                w.push('>');
+            }
+            VTagInner::Textarea { value } => {
+                w.push('>');
+                if let Some(value) = &value.0 {
+                    super::escape_html(w, value);
+                }
+                w.push_str("</textarea>");
+            }
+            VTagInner::Other { children, .. } => {
+                w.push('>');
+                if !VOID_ELEMENTS.contains(&&*tag.to_ascii_lowercase()) {
+                    children.render_to_string(w, parent_scope);
+                    w.push_str("</");
+                    w.push_str(tag);
+                    w.push('>');
+                }
+            }
+        }
+    }
 }
 
+/// Elements which can not have any children and must not have a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
 impl VDiff for VTag {
     /// Remove VTag from parent.
     fn detach(&mut self, parent: &Element) {
@@ -541,6 +536,7 @@ impl VDiff for VTag {
             .reference
             .take()
             .expect("tried to remove not rendered VTag from DOM");
+        self.listeners.unregister();
 
         // recursively remove its children
         if let VTagInner::Other { children, .. } = &mut self.inner {
@@ -593,7 +589,7 @@ impl VDiff for VTag {
                     }
                 } else {
                     let el = self.create_element(parent);
-                    super::insert_node(&el, parent, Some(&ancestor.first_node()));
+                    super::insert_node(&el, parent, ancestor.first_node().as_ref());
                     ancestor.detach(parent);
                     (None, el)
                 }
@@ -657,6 +653,64 @@ impl VDiff for VTag {
         self.reference = el.into();
         self.node_ref.clone()
     }
+
+    /// Adopts the element at `fragment` if it has the same tag and hydrates its children.
+    /// Attributes, values and listeners are applied as if the element was newly created.
+    fn hydrate(
+        &mut self,
+        parent_scope: &AnyScope,
+        parent: &Element,
+        fragment: &mut Option<Node>,
+        next_sibling: NodeRef,
+    ) -> NodeRef {
+        let el = match fragment.clone().map(JsCast::dyn_into::<Element>) {
+            Some(Ok(el)) if el.tag_name().eq_ignore_ascii_case(self.tag()) => el,
+            _ => {
+                super::report_hydration_mismatch(
+                    format_args!("<{}> element", self.tag()),
+                    fragment.as_ref(),
+                );
+                let next = match fragment {
+                    Some(node) => NodeRef::new(node.clone()),
+                    None => next_sibling,
+                };
+                return self.apply(parent_scope, parent, next, None);
+            }
+        };
+        *fragment = el.next_sibling();
+
+        #[cfg(debug_assertions)]
+        for (key, value) in self.attributes.iter() {
+            if el.get_attribute(key).as_deref() != Some(value) {
+                super::report_hydration_mismatch(
+                    format_args!("attribute {}=\"{}\"", key, value),
+                    Some(&el),
+                );
+            }
+        }
+        self.attributes.apply(&el);
+        self.listeners.apply(&el);
+
+        match &mut self.inner {
+            VTagInner::Input(f) => {
+                f.apply(el.unchecked_ref());
+            }
+            VTagInner::Textarea { value } => {
+                value.apply(el.unchecked_ref());
+            }
+            VTagInner::Other { children, .. } => {
+                let mut fragment = el.first_child();
+                if !children.is_empty() {
+                    children.hydrate(parent_scope, &el, &mut fragment, NodeRef::default());
+                }
+                super::remove_unhydrated(&el, fragment);
+            }
+        }
+
+        self.node_ref.set(Some(el.deref().clone()));
+        self.reference = el.into();
+        self.node_ref.clone()
+    }
 }
 
 impl PartialEq for VTag {
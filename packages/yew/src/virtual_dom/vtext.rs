@@ -5,7 +5,8 @@ use crate::html::{AnyScope, NodeRef};
 use crate::utils::document;
 use log::warn;
 use std::cmp::PartialEq;
-use web_sys::{Element, Text as TextNode};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, Node, Text as TextNode};
 
 /// A type for a virtual
 /// [`TextNode`](https://developer.mozilla.org/en-US/docs/Web/API/Document/createTextNode)
@@ -26,6 +27,11 @@ impl VText {
             reference: None,
         }
     }
+
+    /// Writes the escaped text to `w`.
+    pub(crate) fn render_to_string(&self, w: &mut String) {
+        super::escape_html(w, &self.text);
+    }
 }
 
 impl std::fmt::Debug for VText {
@@ -84,6 +90,51 @@ impl VDiff for VText {
         self.reference = Some(text_node.clone());
         NodeRef::new(text_node.into())
     }
+
+    /// Adopts the text node at `fragment`. Adjacent text is merged into a single text node by the
+    /// HTML parser, so a text node that starts with the expected text is split in two.
+    fn hydrate(
+        &mut self,
+        _parent_scope: &AnyScope,
+        parent: &Element,
+        fragment: &mut Option<Node>,
+        _next_sibling: NodeRef,
+    ) -> NodeRef {
+        let text_node = match fragment.clone().map(JsCast::dyn_into::<TextNode>) {
+            Some(Ok(text_node)) => {
+                let content = text_node.text_content().unwrap_or_default();
+                if content.len() > self.text.len() && content.starts_with(&*self.text) {
+                    let offset = self.text.encode_utf16().count() as u32;
+                    text_node
+                        .split_text(offset)
+                        .expect("failed to split text node");
+                } else if content != self.text {
+                    super::report_hydration_mismatch(
+                        format_args!("text {:?}", self.text),
+                        Some(&text_node),
+                    );
+                    text_node.set_node_value(Some(&self.text));
+                }
+                *fragment = text_node.next_sibling();
+                text_node
+            }
+            _ => {
+                // Empty text is not rendered by the server, so it is not a mismatch
+                if !self.text.is_empty() {
+                    super::report_hydration_mismatch(
+                        format_args!("text {:?}", self.text),
+                        fragment.as_ref(),
+                    );
+                }
+                let text_node = document().create_text_node(&self.text);
+                super::insert_node(&text_node, parent, fragment.as_ref());
+                text_node
+            }
+        };
+
+        self.reference = Some(text_node.clone());
+        NodeRef::new(text_node.into())
+    }
 }
 
 impl PartialEq for VText {
@@ -0,0 +1,283 @@
+mod common;
+
+use common::obtain_result_by_id;
+use std::cell::Cell;
+use wasm_bindgen_test::*;
+use yew::functional::{use_effect, use_state, FunctionComponent, FunctionProvider};
+use yew::{html, Component, ComponentLink, ContextProvider, Html, Properties, ShouldRender};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Clone, Debug, PartialEq)]
+struct AppContext {
+    name: String,
+    count: u32,
+}
+
+struct CountConsumer {
+    link: ComponentLink<Self>,
+    renders: Cell<u32>,
+}
+
+impl Component for CountConsumer {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            renders: Cell::new(0),
+        }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        self.renders.set(self.renders.get() + 1);
+        let ctx = self.link.consume_context::<AppContext>().unwrap();
+        html! {
+            <div id="count">{format!("{} {}", ctx.count, self.renders.get())}</div>
+        }
+    }
+}
+
+struct NameConsumer {
+    link: ComponentLink<Self>,
+    renders: Cell<u32>,
+}
+
+impl Component for NameConsumer {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            renders: Cell::new(0),
+        }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        self.renders.set(self.renders.get() + 1);
+        let name = self
+            .link
+            .consume_context_selector(|ctx: &AppContext| ctx.name.clone())
+            .unwrap();
+        html! {
+            <div id="name">{format!("{} {}", name, self.renders.get())}</div>
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn struct_components_are_rerendered_when_context_changes() {
+    struct AppFunction {}
+    impl FunctionProvider for AppFunction {
+        type TProps = ();
+
+        fn run(_props: &Self::TProps) -> Html {
+            type AppContextProvider = ContextProvider<AppContext>;
+            let count = use_state(|| 0);
+            {
+                let count = count.clone();
+                use_effect(move || {
+                    if *count < 2 {
+                        count.set(*count + 1);
+                    }
+                    || {}
+                });
+            }
+            let context = AppContext {
+                name: "yew".into(),
+                count: *count,
+            };
+            html! {
+                <AppContextProvider {context}>
+                    <CountConsumer />
+                    <NameConsumer />
+                </AppContextProvider>
+            }
+        }
+    }
+    type App = FunctionComponent<AppFunction>;
+    yew::start_app_in_element::<App>(yew::utils::document().get_element_by_id("output").unwrap());
+
+    assert_eq!(obtain_result_by_id("count"), "2 3");
+    // The selected name didn't change, so the component was only rendered once
+    assert_eq!(obtain_result_by_id("name"), "yew 1");
+}
+
+#[derive(Clone, PartialEq, Properties)]
+struct SelectingConsumerProps {
+    select_name: bool,
+}
+
+struct SelectingConsumer {
+    link: ComponentLink<Self>,
+    props: SelectingConsumerProps,
+}
+
+impl Component for SelectingConsumer {
+    type Message = ();
+    type Properties = SelectingConsumerProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let select_name = self.props.select_name;
+        let selected = self
+            .link
+            .consume_context_selector(move |ctx: &AppContext| {
+                if select_name {
+                    ctx.name.clone()
+                } else {
+                    ctx.count.to_string()
+                }
+            })
+            .unwrap();
+        html! {
+            <div id="selected">{selected}</div>
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn context_changes_are_detected_with_latest_selector() {
+    struct AppFunction {}
+    impl FunctionProvider for AppFunction {
+        type TProps = ();
+
+        fn run(_props: &Self::TProps) -> Html {
+            type AppContextProvider = ContextProvider<AppContext>;
+            let stage = use_state(|| 0);
+            {
+                let stage = stage.clone();
+                use_effect(move || {
+                    if *stage < 2 {
+                        stage.set(*stage + 1);
+                    }
+                    || {}
+                });
+            }
+            // The consumer selects the name before it changes, the count stays the same
+            let context = AppContext {
+                name: if *stage < 2 { "yew" } else { "yew-router" }.into(),
+                count: 0,
+            };
+            html! {
+                <AppContextProvider {context}>
+                    <SelectingConsumer select_name={*stage > 0} />
+                </AppContextProvider>
+            }
+        }
+    }
+    type App = FunctionComponent<AppFunction>;
+    yew::start_app_in_element::<App>(yew::utils::document().get_element_by_id("output").unwrap());
+
+    assert_eq!(obtain_result_by_id("selected"), "yew-router");
+}
+
+#[wasm_bindgen_test]
+fn replaced_selector_is_compared_against_its_own_value() {
+    struct AppFunction {}
+    impl FunctionProvider for AppFunction {
+        type TProps = ();
+
+        fn run(_props: &Self::TProps) -> Html {
+            type AppContextProvider = ContextProvider<AppContext>;
+            let stage = use_state(|| 0);
+            {
+                let stage = stage.clone();
+                use_effect(move || {
+                    if *stage < 2 {
+                        stage.set(*stage + 1);
+                    }
+                    || {}
+                });
+            }
+            // The new name equals the count the first selector returned
+            let context = AppContext {
+                name: if *stage < 2 { "yew" } else { "0" }.into(),
+                count: 0,
+            };
+            html! {
+                <AppContextProvider {context}>
+                    <SelectingConsumer select_name={*stage > 0} />
+                </AppContextProvider>
+            }
+        }
+    }
+    type App = FunctionComponent<AppFunction>;
+    yew::start_app_in_element::<App>(yew::utils::document().get_element_by_id("output").unwrap());
+
+    assert_eq!(obtain_result_by_id("selected"), "0");
+}
+
+struct OptionalConsumer {
+    link: ComponentLink<Self>,
+}
+
+impl Component for OptionalConsumer {
+    type Message = ();
+    type Properties = ();
+
+    fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link }
+    }
+
+    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let text = match self.link.consume_context::<AppContext>() {
+            Some(ctx) => ctx.name,
+            None => "none".to_string(),
+        };
+        html! {
+            <div id="result">{text}</div>
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn struct_components_without_provider_get_no_context() {
+    yew::start_app_in_element::<OptionalConsumer>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    assert_eq!(obtain_result_by_id("result"), "none");
+}
@@ -0,0 +1,494 @@
+mod common;
+
+use common::obtain_result;
+use gloo::events::EventListener;
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use web_sys::{
+    CustomEvent, CustomEventInit, Event, HtmlElement, HtmlInputElement, HtmlSelectElement,
+    MouseEvent, ShadowRootInit, ShadowRootMode,
+};
+use yew::functional::{use_ref, use_state, FunctionComponent, FunctionProvider};
+use yew::html::WithOptions;
+use yew::{
+    html, Callback, ChangeData, Component, ComponentLink, Html, NodeRef, ShouldRender, TargetCast,
+};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn click(id: &str) {
+    yew::utils::document()
+        .get_element_by_id(id)
+        .unwrap()
+        .unchecked_into::<HtmlElement>()
+        .click();
+}
+
+#[wasm_bindgen_test]
+fn events_bubble_to_ancestors() {
+    struct BubblingFunction {}
+    impl FunctionProvider for BubblingFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let clicks = use_state(Vec::new);
+            let log = |name: &'static str| {
+                let clicks = clicks.clone();
+                Callback::from(move |_: MouseEvent| {
+                    let mut next = (*clicks).clone();
+                    next.push(name);
+                    clicks.set(next);
+                })
+            };
+            return html! {
+                <div onclick={log("outer")}>
+                    <div onclick={log("inner")}>
+                        <button id="button">{"Click"}</button>
+                    </div>
+                    <div id="result">{clicks.join(",")}</div>
+                </div>
+            };
+        }
+    }
+    type BubblingComponent = FunctionComponent<BubblingFunction>;
+    yew::start_app_in_element::<BubblingComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    click("button");
+    assert_eq!(obtain_result().as_str(), "inner,outer");
+}
+
+#[wasm_bindgen_test]
+fn stop_propagation_stops_bubbling() {
+    struct StoppingFunction {}
+    impl FunctionProvider for StoppingFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            let outer = {
+                let counter = counter.clone();
+                Callback::from(move |_: MouseEvent| counter.set(*counter + 10))
+            };
+            let inner = {
+                let counter = counter.clone();
+                Callback::from(move |e: MouseEvent| {
+                    e.stop_propagation();
+                    counter.set(*counter + 1)
+                })
+            };
+            return html! {
+                <div onclick={outer}>
+                    <button id="stopping" onclick={inner}>{"Click"}</button>
+                    <div id="result">{*counter}</div>
+                </div>
+            };
+        }
+    }
+    type StoppingComponent = FunctionComponent<StoppingFunction>;
+    yew::start_app_in_element::<StoppingComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    click("stopping");
+    assert_eq!(obtain_result().as_str(), "1");
+}
+
+#[wasm_bindgen_test]
+fn stopped_events_only_reach_native_listeners_below_the_root() {
+    struct StoppingFunction {}
+    impl FunctionProvider for StoppingFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let onclick = Callback::from(|e: MouseEvent| e.stop_propagation());
+            html! {
+                <div id="native">
+                    <button id="stopping" {onclick}>{"Click"}</button>
+                </div>
+            }
+        }
+    }
+    type StoppingComponent = FunctionComponent<StoppingFunction>;
+    yew::start_app_in_element::<StoppingComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    let below = Rc::new(Cell::new(0));
+    let above = Rc::new(Cell::new(0));
+    let _below_listener = {
+        let below = below.clone();
+        let el = yew::utils::document().get_element_by_id("native").unwrap();
+        EventListener::new(&el, "click", move |_| below.set(below.get() + 1))
+    };
+    let _above_listener = {
+        let above = above.clone();
+        let body = yew::utils::document().body().unwrap();
+        EventListener::new(&body, "click", move |_| above.set(above.get() + 1))
+    };
+
+    click("stopping");
+    assert_eq!(below.get(), 1);
+    assert_eq!(above.get(), 0);
+}
+
+#[wasm_bindgen_test]
+fn apps_mounted_in_shadow_roots_receive_events() {
+    struct CounterFunction {}
+    impl FunctionProvider for CounterFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            let onclick = {
+                let counter = counter.clone();
+                Callback::from(move |_| counter.set(*counter + 1))
+            };
+            html! { <button {onclick}>{*counter}</button> }
+        }
+    }
+    type CounterComponent = FunctionComponent<CounterFunction>;
+
+    let document = yew::utils::document();
+    let host = document.create_element("div").unwrap();
+    document
+        .get_element_by_id("output")
+        .unwrap()
+        .append_child(&host)
+        .unwrap();
+    let shadow_root = host
+        .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+        .unwrap();
+    let root = document.create_element("div").unwrap();
+    shadow_root.append_child(&root).unwrap();
+    yew::start_app_in_element::<CounterComponent>(root.clone());
+
+    let button = root.query_selector("button").unwrap().unwrap();
+    button.unchecked_ref::<HtmlElement>().click();
+    assert_eq!(button.inner_html(), "1");
+}
+
+#[wasm_bindgen_test]
+fn apps_mounted_again_after_destroy_receive_events_once() {
+    thread_local! {
+        static CLICKS: Cell<u32> = Cell::new(0);
+    }
+
+    struct ClickFunction {}
+    impl FunctionProvider for ClickFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let onclick =
+                Callback::from(|_: MouseEvent| CLICKS.with(|clicks| clicks.set(clicks.get() + 1)));
+            html! { <button {onclick}>{"Click"}</button> }
+        }
+    }
+    type ClickComponent = FunctionComponent<ClickFunction>;
+
+    let document = yew::utils::document();
+    let root = document.create_element("div").unwrap();
+    document
+        .get_element_by_id("output")
+        .unwrap()
+        .append_child(&root)
+        .unwrap();
+
+    for _ in 0..2 {
+        yew::start_app_in_element::<ClickComponent>(root.clone()).destroy();
+    }
+    yew::start_app_in_element::<ClickComponent>(root.clone());
+
+    let button = root.query_selector("button").unwrap().unwrap();
+    button.unchecked_ref::<HtmlElement>().click();
+    assert_eq!(CLICKS.with(Cell::get), 1);
+}
+
+#[wasm_bindgen_test]
+fn listeners_added_on_rerender_are_dispatched() {
+    struct AddingComponent {
+        link: ComponentLink<Self>,
+        enabled: bool,
+        clicks: u32,
+    }
+
+    enum Msg {
+        Enable,
+        Click,
+    }
+
+    impl Component for AddingComponent {
+        type Message = Msg;
+        type Properties = ();
+
+        fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+            Self {
+                link,
+                enabled: false,
+                clicks: 0,
+            }
+        }
+
+        fn update(&mut self, msg: Self::Message) -> ShouldRender {
+            match msg {
+                Msg::Enable => self.enabled = true,
+                Msg::Click => self.clicks += 1,
+            }
+            true
+        }
+
+        fn change(&mut self, _: Self::Properties) -> ShouldRender {
+            false
+        }
+
+        fn view(&self) -> Html {
+            // The button already has a registered listener when `onclick` is added
+            let onmouseover = Callback::from(|_: MouseEvent| ());
+            let onclick = if self.enabled {
+                Some(self.link.callback(|_: MouseEvent| Msg::Click))
+            } else {
+                None
+            };
+            html! {
+                <div>
+                    <button id="adding" {onmouseover} {onclick}>{"Click"}</button>
+                    <div id="result">{self.clicks}</div>
+                </div>
+            }
+        }
+    }
+
+    let app = yew::start_app_in_element::<AddingComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    click("adding");
+    assert_eq!(obtain_result().as_str(), "0");
+
+    app.send_message(Msg::Enable);
+    click("adding");
+    assert_eq!(obtain_result().as_str(), "1");
+}
+
+#[wasm_bindgen_test]
+fn capturing_listeners_run_before_descendants() {
+    struct CapturingFunction {}
+    impl FunctionProvider for CapturingFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let clicks = use_state(Vec::new);
+            let log = |name: &'static str| {
+                let clicks = clicks.clone();
+                Callback::from(move |_: MouseEvent| {
+                    let mut next = (*clicks).clone();
+                    next.push(name);
+                    clicks.set(next);
+                })
+            };
+            html! {
+                <div onclick={WithOptions::capture(log("outer"))}>
+                    <button id="capturing" onclick={log("inner")}>{"Click"}</button>
+                    <div id="result">{clicks.join(",")}</div>
+                </div>
+            }
+        }
+    }
+    type CapturingComponent = FunctionComponent<CapturingFunction>;
+    yew::start_app_in_element::<CapturingComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    click("capturing");
+    assert_eq!(obtain_result().as_str(), "outer,inner");
+}
+
+#[wasm_bindgen_test]
+fn custom_events_are_dispatched_from_node_refs() {
+    struct CustomEventFunction {}
+    impl FunctionProvider for CustomEventFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let detail = use_state(String::new);
+            let node_ref = use_ref(NodeRef::default).borrow().clone();
+            let onpicked = {
+                let detail = detail.clone();
+                Callback::from(move |e: CustomEvent| detail.set(e.detail().as_string().unwrap()))
+            };
+            let onclick = {
+                let node_ref = node_ref.clone();
+                Callback::from(move |_: MouseEvent| {
+                    node_ref.dispatch_custom_event("color-picked", &JsValue::from_str("red"));
+                })
+            };
+            html! {
+                <div on:color-picked={onpicked}>
+                    <button id="picker" ref={node_ref} {onclick}>{"Pick"}</button>
+                    <div id="result">{(*detail).clone()}</div>
+                </div>
+            }
+        }
+    }
+    type CustomEventComponent = FunctionComponent<CustomEventFunction>;
+    yew::start_app_in_element::<CustomEventComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    click("picker");
+    assert_eq!(obtain_result().as_str(), "red");
+}
+
+#[wasm_bindgen_test]
+fn custom_events_are_received_inside_shadow_roots() {
+    struct CustomEventFunction {}
+    impl FunctionProvider for CustomEventFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let detail = use_state(String::new);
+            let onpicked = {
+                let detail = detail.clone();
+                Callback::from(move |e: CustomEvent| detail.set(e.detail().as_string().unwrap()))
+            };
+            html! {
+                <div on:color-picked={onpicked}>
+                    <button>{(*detail).clone()}</button>
+                </div>
+            }
+        }
+    }
+    type CustomEventComponent = FunctionComponent<CustomEventFunction>;
+
+    let document = yew::utils::document();
+    let host = document.create_element("div").unwrap();
+    document
+        .get_element_by_id("output")
+        .unwrap()
+        .append_child(&host)
+        .unwrap();
+    let shadow_root = host
+        .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+        .unwrap();
+    let root = document.create_element("div").unwrap();
+    shadow_root.append_child(&root).unwrap();
+    yew::start_app_in_element::<CustomEventComponent>(root.clone());
+
+    // Neither bubbling nor composed, so the event never leaves the element it is dispatched on
+    let init = CustomEventInit::new();
+    init.set_detail(&JsValue::from_str("red"));
+    let event = CustomEvent::new_with_event_init_dict("color-picked", &init).unwrap();
+    let picker = root.first_element_child().unwrap();
+    picker.dispatch_event(&event).unwrap();
+
+    let button = root.query_selector("button").unwrap().unwrap();
+    assert_eq!(button.inner_html(), "red");
+}
+
+#[wasm_bindgen_test]
+fn custom_events_of_another_type_are_not_passed_to_listeners() {
+    struct CustomEventFunction {}
+    impl FunctionProvider for CustomEventFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let detail = use_state(|| "none".to_string());
+            let onpicked = {
+                let detail = detail.clone();
+                Callback::from(move |e: CustomEvent| detail.set(e.detail().as_string().unwrap()))
+            };
+            html! {
+                <div id="picker" on:color-picked={onpicked}>
+                    <div id="result">{(*detail).clone()}</div>
+                </div>
+            }
+        }
+    }
+    type CustomEventComponent = FunctionComponent<CustomEventFunction>;
+    yew::start_app_in_element::<CustomEventComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    let picker = yew::utils::document().get_element_by_id("picker").unwrap();
+    picker
+        .dispatch_event(&Event::new("color-picked").unwrap())
+        .unwrap();
+    assert_eq!(obtain_result().as_str(), "none");
+
+    let init = CustomEventInit::new();
+    init.set_detail(&JsValue::from_str("red"));
+    let event = CustomEvent::new_with_event_init_dict("color-picked", &init).unwrap();
+    picker.dispatch_event(&event).unwrap();
+    assert_eq!(obtain_result().as_str(), "red");
+}
+
+#[wasm_bindgen_test]
+fn event_targets_are_cast_to_elements() {
+    struct TargetCastFunction {}
+    impl FunctionProvider for TargetCastFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let result = use_state(String::new);
+            let onclick = {
+                let result = result.clone();
+                Callback::from(move |e: MouseEvent| {
+                    let checked = e.target_into::<HtmlInputElement>().unwrap().checked();
+                    let error = e.target_into::<HtmlSelectElement>().unwrap_err();
+                    result.set(format!("{} {}", checked, error));
+                })
+            };
+            html! {
+                <div>
+                    <input id="checkbox" type="checkbox" {onclick} />
+                    <div id="result">{(*result).clone()}</div>
+                </div>
+            }
+        }
+    }
+    type TargetCastComponent = FunctionComponent<TargetCastFunction>;
+    yew::start_app_in_element::<TargetCastComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    click("checkbox");
+    assert_eq!(
+        obtain_result().as_str(),
+        "true expected the event target to be a `HtmlSelectElement`, but found &lt;input&gt;"
+    );
+}
+
+#[wasm_bindgen_test]
+fn change_data_has_the_checked_state() {
+    struct CheckboxFunction {}
+    impl FunctionProvider for CheckboxFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let result = use_state(String::new);
+            let onchange = {
+                let result = result.clone();
+                Callback::from(move |data: ChangeData| {
+                    result.set(format!("{:?} {:?}", data.checked(), data.value_as_number()));
+                })
+            };
+            html! {
+                <div>
+                    <input id="checkbox" type="checkbox" {onchange} />
+                    <div id="result">{(*result).clone()}</div>
+                </div>
+            }
+        }
+    }
+    type CheckboxComponent = FunctionComponent<CheckboxFunction>;
+    yew::start_app_in_element::<CheckboxComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    click("checkbox");
+    assert_eq!(obtain_result().as_str(), "Some(true) None");
+}
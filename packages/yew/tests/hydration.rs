@@ -0,0 +1,121 @@
+mod common;
+
+use common::obtain_result;
+use std::cell::RefCell;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::functional::{use_ref, use_state, FunctionComponent, FunctionProvider};
+use yew::suspense::{Suspense, Suspension, SuspensionHandle};
+use yew::{function_component, html, Callback, Html, HtmlResult, ServerRenderer};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+struct CounterFunction {}
+impl FunctionProvider for CounterFunction {
+    type TProps = ();
+
+    fn run(_: &Self::TProps) -> Html {
+        let counter = use_state(|| 0);
+        let onclick = {
+            let counter = counter.clone();
+            Callback::from(move |_| counter.set(*counter + 1))
+        };
+        return html! {
+            <div>
+                {"Test Output: "}
+                <button id="increment" {onclick}>{"+1"}</button>
+                <div id="result">{*counter}</div>
+            </div>
+        };
+    }
+}
+type Counter = FunctionComponent<CounterFunction>;
+
+#[wasm_bindgen_test]
+fn hydration_reuses_server_rendered_nodes() {
+    let output = yew::utils::document().get_element_by_id("output").unwrap();
+    output.set_inner_html(&ServerRenderer::<Counter>::new().render());
+    let button = yew::utils::document()
+        .get_element_by_id("increment")
+        .unwrap();
+
+    yew::hydrate_app_in_element::<Counter>(output);
+    let hydrated_button = yew::utils::document()
+        .get_element_by_id("increment")
+        .unwrap();
+    assert!(button.is_same_node(Some(&hydrated_button)));
+    assert_eq!(obtain_result().as_str(), "0");
+
+    button.unchecked_into::<HtmlElement>().click();
+    assert_eq!(obtain_result().as_str(), "1");
+}
+
+#[wasm_bindgen_test]
+fn hydration_replaces_mismatched_nodes() {
+    let output = yew::utils::document().get_element_by_id("output").unwrap();
+    output.set_inner_html("<span>Stale</span><p>Leftover</p>");
+
+    yew::hydrate_app_in_element::<Counter>(output.clone());
+    assert_eq!(obtain_result().as_str(), "0");
+    assert_eq!(output.child_element_count(), 1);
+    assert_eq!(output.first_element_child().unwrap().tag_name(), "DIV");
+}
+
+thread_local! {
+    static HANDLES: RefCell<Vec<SuspensionHandle>> = RefCell::new(Vec::new());
+}
+
+#[function_component(Content)]
+fn content() -> HtmlResult {
+    let suspension = use_ref(|| {
+        let (suspension, handle) = Suspension::new();
+        HANDLES.with(|handles| handles.borrow_mut().push(handle));
+        suspension
+    });
+    let suspension = suspension.borrow().clone();
+    if !suspension.resumed() {
+        return Err(suspension);
+    }
+
+    Ok(html! { <div id="content">{"Content"}</div> })
+}
+
+#[function_component(SuspendingApp)]
+fn suspending_app() -> Html {
+    let fallback = html! { <div id="fallback">{"Loading..."}</div> };
+    html! {
+        <div id="result">
+            <Suspense {fallback}>
+                <Content />
+            </Suspense>
+        </div>
+    }
+}
+
+#[wasm_bindgen_test]
+fn hydration_adopts_fallback_of_suspended_children() {
+    let output = yew::utils::document().get_element_by_id("output").unwrap();
+    output.set_inner_html(&ServerRenderer::<SuspendingApp>::new().render());
+    // The suspension of the server is never resumed
+    HANDLES.with(|handles| handles.borrow_mut().drain(..).for_each(std::mem::forget));
+    let fallback = yew::utils::document()
+        .get_element_by_id("fallback")
+        .unwrap();
+
+    yew::hydrate_app_in_element::<SuspendingApp>(output);
+    let hydrated_fallback = yew::utils::document()
+        .get_element_by_id("fallback")
+        .unwrap();
+    assert!(fallback.is_same_node(Some(&hydrated_fallback)));
+    assert_eq!(
+        obtain_result().as_str(),
+        r#"<div id="fallback">Loading...</div>"#
+    );
+
+    HANDLES.with(|handles| handles.borrow_mut().clear());
+    assert_eq!(
+        obtain_result().as_str(),
+        r#"<div id="content">Content</div>"#
+    );
+}
@@ -0,0 +1,186 @@
+mod common;
+
+use common::obtain_result_by_id;
+use std::cell::{Cell, RefCell};
+use wasm_bindgen_test::*;
+use yew::functional::{use_ref, use_state, FunctionComponent, FunctionProvider};
+use yew::suspense::{Suspense, Suspension, SuspensionHandle};
+use yew::{
+    function_component, html, Component, ComponentLink, Html, HtmlResult, Properties, ShouldRender,
+};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static HANDLES: RefCell<Vec<SuspensionHandle>> = RefCell::new(Vec::new());
+}
+
+fn resume_all() {
+    HANDLES.with(|handles| handles.borrow_mut().clear());
+}
+
+fn use_suspension() -> Suspension {
+    let suspension = use_ref(|| {
+        let (suspension, handle) = Suspension::new();
+        HANDLES.with(|handles| handles.borrow_mut().push(handle));
+        suspension
+    });
+    let suspension = suspension.borrow().clone();
+    suspension
+}
+
+#[function_component(Content)]
+fn content() -> HtmlResult {
+    let suspension = use_suspension();
+    if !suspension.resumed() {
+        return Err(suspension);
+    }
+
+    Ok(html! { <div id="content">{"Content"}</div> })
+}
+
+#[wasm_bindgen_test]
+fn suspense_renders_fallback_until_resumed() {
+    struct AppFunction {}
+    impl FunctionProvider for AppFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let fallback = html! { <div id="fallback">{"Loading..."}</div> };
+            html! {
+                <div id="result">
+                    <Suspense {fallback}>
+                        <Content />
+                    </Suspense>
+                </div>
+            }
+        }
+    }
+    type App = FunctionComponent<AppFunction>;
+
+    yew::start_app_in_element::<App>(yew::utils::document().get_element_by_id("output").unwrap());
+    assert_eq!(
+        obtain_result_by_id("result"),
+        r#"<div id="fallback">Loading...</div>"#
+    );
+
+    resume_all();
+    assert_eq!(
+        obtain_result_by_id("result"),
+        r#"<div id="content">Content</div>"#
+    );
+}
+
+#[wasm_bindgen_test]
+fn suspense_preserves_state_of_children() {
+    #[function_component(Counter)]
+    fn counter() -> Html {
+        let counter = use_state(|| 0);
+        if *counter == 0 {
+            counter.set(1);
+        }
+
+        html! { <span id="counter">{*counter}</span> }
+    }
+
+    struct AppFunction {}
+    impl FunctionProvider for AppFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let fallback = html! { <div id="fallback">{"Loading..."}</div> };
+            html! {
+                <div id="result">
+                    <Suspense {fallback}>
+                        <Counter />
+                        <Content />
+                    </Suspense>
+                </div>
+            }
+        }
+    }
+    type App = FunctionComponent<AppFunction>;
+
+    yew::start_app_in_element::<App>(yew::utils::document().get_element_by_id("output").unwrap());
+    assert_eq!(
+        obtain_result_by_id("result"),
+        r#"<div id="fallback">Loading...</div>"#
+    );
+
+    resume_all();
+    assert_eq!(
+        obtain_result_by_id("result"),
+        r#"<span id="counter">1</span><div id="content">Content</div>"#
+    );
+}
+
+#[wasm_bindgen_test]
+fn suspended_component_renders_once_on_resume() {
+    thread_local! {
+        static RENDERS: Cell<u32> = Cell::new(0);
+    }
+
+    #[derive(Clone, PartialEq, Properties)]
+    struct ValueProps {
+        value: u32,
+    }
+
+    #[function_component(Value)]
+    fn value(props: &ValueProps) -> HtmlResult {
+        RENDERS.with(|renders| renders.set(renders.get() + 1));
+        let suspension = use_suspension();
+        if !suspension.resumed() {
+            return Err(suspension);
+        }
+
+        Ok(html! { <div id="content">{props.value}</div> })
+    }
+
+    struct App {
+        value: u32,
+    }
+
+    impl Component for App {
+        type Message = u32;
+        type Properties = ();
+
+        fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
+            Self { value: 1 }
+        }
+
+        fn update(&mut self, value: Self::Message) -> ShouldRender {
+            self.value = value;
+            true
+        }
+
+        fn change(&mut self, _: Self::Properties) -> ShouldRender {
+            false
+        }
+
+        fn view(&self) -> Html {
+            let fallback = html! { <div id="fallback">{"Loading..."}</div> };
+            html! {
+                <div id="result">
+                    <Suspense {fallback}>
+                        <Value value={self.value} />
+                    </Suspense>
+                </div>
+            }
+        }
+    }
+
+    let app = yew::start_app_in_element::<App>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    // the component is rendered again with new props while it is suspended
+    app.send_message(2u32);
+    let renders = RENDERS.with(|renders| renders.get());
+    assert_eq!(renders, 2);
+
+    resume_all();
+    assert_eq!(
+        obtain_result_by_id("result"),
+        r#"<div id="content">2</div>"#
+    );
+    assert_eq!(RENDERS.with(|renders| renders.get()), renders + 1);
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::obtain_result;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wasm_bindgen_test::*;
+use yew::functional::{use_callback, use_state, FunctionComponent, FunctionProvider};
+use yew::{html, Callback, Html, Properties};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn use_callback_works() {
+    static RENDERED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Clone, PartialEq, Properties)]
+    struct Props {
+        callback: Callback<String>,
+    }
+
+    struct ChildFunction {}
+    impl FunctionProvider for ChildFunction {
+        type TProps = Props;
+
+        fn run(_: &Self::TProps) -> Html {
+            RENDERED.fetch_add(1, Ordering::Relaxed);
+            html! {}
+        }
+    }
+    type Child = FunctionComponent<ChildFunction>;
+
+    struct UseCallbackFunction {}
+    impl FunctionProvider for UseCallbackFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            let callback = use_callback(|_: String, _| (), ());
+            if *counter < 5 {
+                counter.set(*counter + 1)
+            }
+            html! {
+                <div>
+                    <Child {callback} />
+                    <div id="result">{*counter}</div>
+                </div>
+            }
+        }
+    }
+    type UseCallbackComponent = FunctionComponent<UseCallbackFunction>;
+    yew::start_app_in_element::<UseCallbackComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "5");
+    // The props of the child never change, so it is only rendered once
+    assert_eq!(RENDERED.load(Ordering::Relaxed), 1);
+}
@@ -4,7 +4,8 @@ use common::obtain_result_by_id;
 use std::rc::Rc;
 use wasm_bindgen_test::*;
 use yew::functional::{
-    use_context, use_effect, use_ref, use_state, FunctionComponent, FunctionProvider,
+    use_context, use_context_selector, use_effect, use_ref, use_state, FunctionComponent,
+    FunctionProvider,
 };
 use yew::{html, Children, ContextProvider, Html, Properties};
 
@@ -294,3 +295,179 @@ fn use_context_update_works() {
         "current: hello world!, total: 4"
     );
 }
+
+#[wasm_bindgen_test]
+fn use_context_selector_rerenders_only_when_selection_changes() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct AppState {
+        name: String,
+        count: u32,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Properties)]
+    struct OutletProps {
+        id: String,
+    }
+
+    struct NameOutletFunction;
+    impl FunctionProvider for NameOutletFunction {
+        type TProps = OutletProps;
+
+        fn run(props: &Self::TProps) -> Html {
+            let counter = use_ref(|| 0);
+            *counter.borrow_mut() += 1;
+
+            let name = use_context_selector(|state: &Rc<AppState>| state.name.clone())
+                .expect("context not passed down");
+
+            html! {
+                <div id={props.id.clone()}>
+                    { format!("current: {}, total: {}", name, counter.borrow()) }
+                </div>
+            }
+        }
+    }
+    type NameOutlet = FunctionComponent<NameOutletFunction>;
+
+    struct CountOutletFunction;
+    impl FunctionProvider for CountOutletFunction {
+        type TProps = OutletProps;
+
+        fn run(props: &Self::TProps) -> Html {
+            let counter = use_ref(|| 0);
+            *counter.borrow_mut() += 1;
+
+            let count = use_context_selector::<Rc<AppState>, _, _>(|state| state.count)
+                .expect("context not passed down");
+
+            html! {
+                <div id={props.id.clone()}>
+                    { format!("current: {}, total: {}", count, counter.borrow()) }
+                </div>
+            }
+        }
+    }
+    type CountOutlet = FunctionComponent<CountOutletFunction>;
+
+    struct TestFunction;
+    impl FunctionProvider for TestFunction {
+        type TProps = ();
+
+        fn run(_props: &Self::TProps) -> Html {
+            type AppStateProvider = ContextProvider<Rc<AppState>>;
+
+            let state = use_state(|| AppState {
+                name: "hello".into(),
+                count: 0,
+            });
+            {
+                let state = state.clone();
+                use_effect(move || {
+                    // Updates the count twice, and then the name once
+                    if state.count < 2 {
+                        state.set(AppState {
+                            count: state.count + 1,
+                            ..(*state).clone()
+                        });
+                    } else if state.name == "hello" {
+                        state.set(AppState {
+                            name: "world".into(),
+                            ..(*state).clone()
+                        });
+                    }
+                    || {}
+                });
+            }
+            html! {
+                <AppStateProvider context={Rc::new((*state).clone())}>
+                    <NameOutlet id="name" />
+                    <CountOutlet id="count" />
+                </AppStateProvider>
+            }
+        }
+    }
+    type TestComponent = FunctionComponent<TestFunction>;
+
+    yew::start_app_in_element::<TestComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    // 1 initial + 1 name update
+    assert_eq!(obtain_result_by_id("name"), "current: world, total: 2");
+
+    // 1 initial + 2 count updates
+    assert_eq!(obtain_result_by_id("count"), "current: 2, total: 3");
+}
+
+#[wasm_bindgen_test]
+fn use_context_selector_uses_latest_selector() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct AppState {
+        name: String,
+        count: u32,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Properties)]
+    struct OutletProps {
+        select_name: bool,
+    }
+
+    struct OutletFunction;
+    impl FunctionProvider for OutletFunction {
+        type TProps = OutletProps;
+
+        fn run(props: &Self::TProps) -> Html {
+            let select_name = props.select_name;
+            let selected = use_context_selector(move |state: &Rc<AppState>| {
+                if select_name {
+                    state.name.clone()
+                } else {
+                    state.count.to_string()
+                }
+            })
+            .expect("context not passed down");
+
+            html! {
+                <div id="selected">{ selected }</div>
+            }
+        }
+    }
+    type Outlet = FunctionComponent<OutletFunction>;
+
+    struct TestFunction;
+    impl FunctionProvider for TestFunction {
+        type TProps = ();
+
+        fn run(_props: &Self::TProps) -> Html {
+            type AppStateProvider = ContextProvider<Rc<AppState>>;
+
+            let stage = use_state(|| 0);
+            {
+                let stage = stage.clone();
+                use_effect(move || {
+                    if *stage < 2 {
+                        stage.set(*stage + 1);
+                    }
+                    || {}
+                });
+            }
+            // The outlet selects the name before it changes to the count selected at first
+            let state = AppState {
+                name: if *stage < 2 { "hello" } else { "0" }.into(),
+                count: 0,
+            };
+            html! {
+                <AppStateProvider context={Rc::new(state)}>
+                    <Outlet select_name={*stage > 0} />
+                </AppStateProvider>
+            }
+        }
+    }
+    type TestComponent = FunctionComponent<TestFunction>;
+
+    yew::start_app_in_element::<TestComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    assert_eq!(obtain_result_by_id("selected"), "0");
+}
@@ -1,13 +1,15 @@
 mod common;
 
 use common::obtain_result;
+use std::cell::RefCell;
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
 use wasm_bindgen_test::*;
 use yew::functional::{
-    use_effect_with_deps, use_ref, use_state, FunctionComponent, FunctionProvider,
+    use_effect, use_effect_with_deps, use_layout_effect, use_layout_effect_with_deps, use_ref,
+    use_state, FunctionComponent, FunctionProvider,
 };
-use yew::{html, Html, Properties};
+use yew::{html, Html, NodeRef, Properties};
 
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
@@ -201,3 +203,79 @@ fn use_effect_refires_on_dependency_change() {
 
     assert_eq!(result.as_str(), "11");
 }
+
+#[wasm_bindgen_test]
+fn layout_effects_run_before_effects() {
+    thread_local! {
+        static ORDER: RefCell<Vec<String>> = RefCell::default();
+    }
+
+    fn log(entry: impl Into<String>) {
+        ORDER.with(|order| order.borrow_mut().push(entry.into()));
+    }
+
+    struct ChildFunction {}
+    impl FunctionProvider for ChildFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            use_effect(|| {
+                log("child effect");
+                || ()
+            });
+            use_layout_effect(|| {
+                log("child layout effect");
+                || ()
+            });
+            html! {}
+        }
+    }
+    type Child = FunctionComponent<ChildFunction>;
+
+    struct ParentFunction {}
+    impl FunctionProvider for ParentFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let node_ref = use_ref(NodeRef::default).borrow().clone();
+            {
+                let node_ref = node_ref.clone();
+                use_layout_effect_with_deps(
+                    move |_| {
+                        // The DOM is already updated when layout effects run
+                        let text = node_ref.get().unwrap().text_content().unwrap();
+                        log(format!("parent layout effect: {}", text));
+                        || ()
+                    },
+                    (),
+                );
+            }
+            use_effect(|| {
+                log("parent effect");
+                || ()
+            });
+            html! {
+                <div>
+                    <Child />
+                    <div id="result" ref={node_ref}>{"rendered"}</div>
+                </div>
+            }
+        }
+    }
+    type Parent = FunctionComponent<ParentFunction>;
+    yew::start_app_in_element::<Parent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    assert_eq!(obtain_result().as_str(), "rendered");
+    let order = ORDER.with(|order| order.borrow().clone());
+    assert_eq!(
+        order,
+        vec![
+            "child layout effect",
+            "parent layout effect: rendered",
+            "child effect",
+            "parent effect",
+        ]
+    );
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::functional::{use_force_update, use_ref, FunctionComponent, FunctionProvider};
+use yew::{html, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn use_force_update_works() {
+    struct UseForceUpdateFunction {}
+    impl FunctionProvider for UseForceUpdateFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let renders = use_ref(|| 0);
+            *renders.borrow_mut() += 1;
+
+            let trigger = use_force_update();
+            let first_trigger = {
+                let trigger = trigger.clone();
+                use_ref(move || trigger)
+            };
+            if *renders.borrow() < 3 {
+                trigger.force_update();
+            }
+
+            let stable = *first_trigger.borrow() == trigger;
+            html! {
+                <div>
+                    {"The test output is: "}
+                    <div id="result">{format!("{} {}", *renders.borrow(), stable)}</div>
+                    {"\n"}
+                </div>
+            }
+        }
+    }
+    type UseForceUpdateComponent = FunctionComponent<UseForceUpdateFunction>;
+    yew::start_app_in_element::<UseForceUpdateComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "3 true");
+}
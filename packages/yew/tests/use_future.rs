@@ -0,0 +1,130 @@
+mod common;
+
+use common::obtain_result;
+use std::cell::Cell;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+use yew::functional::{
+    use_effect_with_deps, use_future_with_deps, use_state, FunctionComponent, FunctionProvider,
+    UseFutureState,
+};
+use yew::{html, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static FINISHED: Cell<u32> = Cell::new(0);
+}
+
+async fn sleep(millis: i32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        yew::utils::window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, millis)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+async fn double(value: i32, millis: i32) -> Result<i32, String> {
+    sleep(millis).await;
+    FINISHED.with(|finished| finished.set(finished.get() + 1));
+    if value < 0 {
+        Err(format!("{} is negative", value))
+    } else {
+        Ok(value * 2)
+    }
+}
+
+fn render_state(state: &UseFutureState<i32, String>) -> Html {
+    let text = match state {
+        UseFutureState::Pending => "pending".to_string(),
+        UseFutureState::Ready(value) => format!("ready {}", value),
+        UseFutureState::Error(error) => format!("error {}", error),
+    };
+    html! {
+        <div>
+            {"The test output is: "}
+            <div id="result">{text}</div>
+            {"\n"}
+        </div>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn use_future_with_deps_renders_output() {
+    struct UseFutureFunction {}
+    impl FunctionProvider for UseFutureFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let state = use_future_with_deps(|value| double(*value, 10), 21);
+            render_state(&state)
+        }
+    }
+    type UseFutureComponent = FunctionComponent<UseFutureFunction>;
+    yew::start_app_in_element::<UseFutureComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    assert_eq!(obtain_result().as_str(), "pending");
+    sleep(50).await;
+    assert_eq!(obtain_result().as_str(), "ready 42");
+}
+
+#[wasm_bindgen_test]
+async fn use_future_with_deps_renders_errors() {
+    struct UseFutureFunction {}
+    impl FunctionProvider for UseFutureFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let state = use_future_with_deps(|value| double(*value, 10), -1);
+            render_state(&state)
+        }
+    }
+    type UseFutureComponent = FunctionComponent<UseFutureFunction>;
+    yew::start_app_in_element::<UseFutureComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    sleep(50).await;
+    assert_eq!(obtain_result().as_str(), "error -1 is negative");
+}
+
+#[wasm_bindgen_test]
+async fn use_future_with_deps_aborts_previous_future() {
+    struct UseFutureFunction {}
+    impl FunctionProvider for UseFutureFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let value = use_state(|| 1);
+            {
+                let value = value.clone();
+                use_effect_with_deps(
+                    move |_| {
+                        value.set(2);
+                        || {}
+                    },
+                    (),
+                );
+            }
+            // The first future takes longer, so its output would replace the output of the
+            // second one if it wasn't aborted
+            let state = use_future_with_deps(
+                |value| double(*value, if *value == 1 { 100 } else { 10 }),
+                *value,
+            );
+            render_state(&state)
+        }
+    }
+    type UseFutureComponent = FunctionComponent<UseFutureFunction>;
+    let finished_before = FINISHED.with(|finished| finished.get());
+    yew::start_app_in_element::<UseFutureComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    sleep(200).await;
+    assert_eq!(obtain_result().as_str(), "ready 4");
+    FINISHED.with(|finished| assert_eq!(finished.get() - finished_before, 1));
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::obtain_result;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wasm_bindgen_test::*;
+use yew::functional::{use_memo, use_state, FunctionComponent, FunctionProvider};
+use yew::{html, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn use_memo_works() {
+    static COMPUTED: AtomicUsize = AtomicUsize::new(0);
+
+    struct UseMemoFunction {}
+    impl FunctionProvider for UseMemoFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            // Only changes once while `counter` goes from 0 to 5
+            let memo = use_memo(
+                |third| {
+                    COMPUTED.fetch_add(1, Ordering::Relaxed);
+                    *third * 2
+                },
+                *counter / 3,
+            );
+            if *counter < 5 {
+                counter.set(*counter + 1)
+            }
+            html! {
+                <div>
+                    {"The test output is: "}
+                    <div id="result">{*memo}</div>
+                    {"\n"}
+                </div>
+            }
+        }
+    }
+    type UseMemoComponent = FunctionComponent<UseMemoFunction>;
+    yew::start_app_in_element::<UseMemoComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "2");
+    assert_eq!(COMPUTED.load(Ordering::Relaxed), 2);
+}
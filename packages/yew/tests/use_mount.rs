@@ -0,0 +1,75 @@
+mod common;
+
+use common::obtain_result;
+use std::cell::RefCell;
+use wasm_bindgen_test::*;
+use yew::functional::{use_mount, use_state, use_unmount, FunctionComponent, FunctionProvider};
+use yew::{html, Callback, Html, Properties};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static LOG: RefCell<Vec<String>> = RefCell::default();
+}
+
+fn log(message: impl Into<String>) {
+    LOG.with(|log| log.borrow_mut().push(message.into()));
+}
+
+#[wasm_bindgen_test]
+fn use_mount_and_use_unmount_run_once() {
+    #[derive(Properties, Clone, PartialEq)]
+    struct ChildProps {
+        hide: Callback<()>,
+    }
+
+    struct ChildFunction {}
+    impl FunctionProvider for ChildFunction {
+        type TProps = ChildProps;
+
+        fn run(props: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            use_mount(|| log("mount"));
+            let last_count = *counter;
+            use_unmount(move || log(format!("unmount {}", last_count)));
+
+            if *counter < 2 {
+                counter.set(*counter + 1);
+            } else {
+                props.hide.emit(());
+            }
+            html! {
+                <div>{*counter}</div>
+            }
+        }
+    }
+    type ChildComponent = FunctionComponent<ChildFunction>;
+
+    struct ParentFunction {}
+    impl FunctionProvider for ParentFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let show = use_state(|| true);
+            let hide = {
+                let show = show.clone();
+                Callback::from(move |_| show.set(false))
+            };
+            let content = if *show {
+                html! { <ChildComponent {hide} /> }
+            } else {
+                html! { "hidden" }
+            };
+            html! {
+                <div id="result">{content}</div>
+            }
+        }
+    }
+    type ParentComponent = FunctionComponent<ParentFunction>;
+    yew::start_app_in_element::<ParentComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    assert_eq!(obtain_result().as_str(), "hidden");
+    LOG.with(|log| assert_eq!(*log.borrow(), vec!["mount", "unmount 2"]));
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::functional::{
+    use_effect, use_node_ref, use_ref, use_state, FunctionComponent, FunctionProvider,
+};
+use yew::{html, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn use_node_ref_works() {
+    struct UseNodeRefFunction {}
+    impl FunctionProvider for UseNodeRefFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let node_ref = use_node_ref();
+            let first_node_ref = {
+                let node_ref = node_ref.clone();
+                use_ref(move || node_ref)
+            };
+            let tag_name = use_state(String::new);
+            {
+                let node_ref = node_ref.clone();
+                let tag_name = tag_name.clone();
+                use_effect(move || {
+                    if tag_name.is_empty() {
+                        tag_name.set(node_ref.cast::<Element>().unwrap().tag_name());
+                    }
+                    || {}
+                });
+            }
+            let stable = *first_node_ref.borrow() == node_ref;
+            html! {
+                <div ref={node_ref}>
+                    {"The test output is: "}
+                    <div id="result">{format!("{} {}", *tag_name, stable)}</div>
+                    {"\n"}
+                </div>
+            }
+        }
+    }
+    type UseNodeRefComponent = FunctionComponent<UseNodeRefFunction>;
+    yew::start_app_in_element::<UseNodeRefComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "DIV true");
+}
@@ -0,0 +1,38 @@
+mod common;
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::functional::{use_previous, use_state, FunctionComponent, FunctionProvider};
+use yew::{html, Html};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn use_previous_returns_value_of_previous_render() {
+    struct UsePreviousFunction {}
+    impl FunctionProvider for UsePreviousFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            let previous = use_previous(*counter);
+            if *counter < 3 {
+                counter.set(*counter + 1);
+            }
+            html! {
+                <div>
+                    {"The test output is: "}
+                    <div id="result">{format!("{:?} {}", previous, *counter)}</div>
+                    {"\n"}
+                </div>
+            }
+        }
+    }
+    type UsePreviousComponent = FunctionComponent<UsePreviousFunction>;
+    yew::start_app_in_element::<UsePreviousComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "Some(2) 3");
+}
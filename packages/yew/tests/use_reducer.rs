@@ -1,11 +1,13 @@
 mod common;
 
-use common::obtain_result;
+use common::{obtain_result, obtain_result_by_id};
+use std::rc::Rc;
 use wasm_bindgen_test::*;
 use yew::functional::{
-    use_effect_with_deps, use_reducer_with_init, FunctionComponent, FunctionProvider,
+    use_effect, use_effect_with_deps, use_reducer, use_reducer_with_init, use_ref, use_state,
+    FunctionComponent, FunctionProvider, Reducible, UseReducerDispatcher,
 };
-use yew::{html, Html};
+use yew::{html, Html, Properties};
 
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
@@ -53,3 +55,150 @@ fn use_reducer_works() {
 
     assert_eq!(result.as_str(), "11");
 }
+
+#[wasm_bindgen_test]
+fn use_reducer_with_init_uses_latest_reducer() {
+    struct LatestReducerFunction {}
+    impl FunctionProvider for LatestReducerFunction {
+        type TProps = ();
+        fn run(_: &Self::TProps) -> Html {
+            let step = use_state(|| 1);
+            let step_value = *step;
+            let counter = use_reducer_with_init(
+                move |prev: Rc<i32>, _: ()| *prev + step_value,
+                0,
+                |initial: i32| initial,
+            );
+
+            {
+                let counter = counter.clone();
+                use_effect_with_deps(
+                    move |step_value| {
+                        // The action is dispatched after the step was changed
+                        if *step_value == 1 {
+                            step.set(10);
+                        } else {
+                            counter.dispatch(());
+                        }
+                        || {}
+                    },
+                    step_value,
+                );
+            }
+            html! { <div id="result">{*counter}</div> }
+        }
+    }
+    type LatestReducerComponent = FunctionComponent<LatestReducerFunction>;
+    yew::start_app_in_element::<LatestReducerComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    let result = obtain_result();
+
+    assert_eq!(result.as_str(), "10");
+}
+
+#[derive(Debug)]
+struct CounterState {
+    counter: i32,
+}
+
+impl Reducible for CounterState {
+    type Action = i32;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        if action == 0 {
+            return self;
+        }
+        Self {
+            counter: self.counter + action,
+        }
+        .into()
+    }
+}
+
+#[wasm_bindgen_test]
+fn use_reducer_with_reducible_state_works() {
+    struct UseReducerFunction {}
+    impl FunctionProvider for UseReducerFunction {
+        type TProps = ();
+        fn run(_: &Self::TProps) -> Html {
+            let renders = use_ref(|| 0);
+            *renders.borrow_mut() += 1;
+
+            let counter = use_reducer(|| CounterState { counter: 10 });
+            let counter_clone = counter.clone();
+            use_effect(move || {
+                // Only the first render increments the counter, the following ones return the
+                // unchanged state
+                let action = if counter_clone.counter == 10 { 1 } else { 0 };
+                counter_clone.dispatch(action);
+                || {}
+            });
+            html! {
+                <div>
+                    {"The test result is"}
+                    <div id="result">{format!("{} {}", counter.counter, *renders.borrow())}</div>
+                    {"\n"}
+                </div>
+            }
+        }
+    }
+    type UseReducerComponent = FunctionComponent<UseReducerFunction>;
+    yew::start_app_in_element::<UseReducerComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    let result = obtain_result();
+
+    assert_eq!(result.as_str(), "11 2");
+}
+
+#[wasm_bindgen_test]
+fn use_reducer_dispatcher_does_not_rerender_children() {
+    #[derive(Properties, Clone, PartialEq)]
+    struct ChildProps {
+        dispatcher: UseReducerDispatcher<i32>,
+    }
+
+    struct ChildFunction {}
+    impl FunctionProvider for ChildFunction {
+        type TProps = ChildProps;
+        fn run(props: &Self::TProps) -> Html {
+            let renders = use_ref(|| 0);
+            *renders.borrow_mut() += 1;
+
+            let dispatcher = props.dispatcher.clone();
+            use_effect_with_deps(
+                move |_| {
+                    dispatcher.dispatch(1);
+                    || {}
+                },
+                (),
+            );
+            html! {
+                <div id="result">{*renders.borrow()}</div>
+            }
+        }
+    }
+    type ChildComponent = FunctionComponent<ChildFunction>;
+
+    struct ParentFunction {}
+    impl FunctionProvider for ParentFunction {
+        type TProps = ();
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_reducer(|| CounterState { counter: 0 });
+            html! {
+                <div>
+                    <div id="counter">{counter.counter}</div>
+                    <ChildComponent dispatcher={counter.dispatcher()} />
+                </div>
+            }
+        }
+    }
+    type ParentComponent = FunctionComponent<ParentFunction>;
+    yew::start_app_in_element::<ParentComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+
+    assert_eq!(obtain_result_by_id("counter").as_str(), "1");
+    assert_eq!(obtain_result().as_str(), "1");
+}
@@ -2,7 +2,10 @@ mod common;
 
 use common::obtain_result;
 use wasm_bindgen_test::*;
-use yew::functional::{use_effect_with_deps, use_state, FunctionComponent, FunctionProvider};
+use yew::functional::{
+    use_effect, use_effect_with_deps, use_ref, use_state, use_state_eq, FunctionComponent,
+    FunctionProvider,
+};
 use yew::{html, Html};
 
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
@@ -79,3 +82,104 @@ fn multiple_use_state_setters() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "11");
 }
+
+#[wasm_bindgen_test]
+fn use_state_updates_use_latest_value() {
+    struct UseStateFunction {}
+    impl FunctionProvider for UseStateFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let counter = use_state(|| 0);
+            let counter_clone = counter.clone();
+            use_effect_with_deps(
+                move |_| {
+                    counter_clone.update(|prev| prev + 1);
+                    counter_clone.update(|prev| prev + 1);
+                    || {}
+                },
+                (),
+            );
+            html! {
+                <div>
+                    {"Test Output: "}
+                    <div id="result">{*counter}</div>
+                    {"\n"}
+                </div>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<UseStateFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "2");
+}
+
+#[wasm_bindgen_test]
+fn use_state_set_if_neq_skips_equal_values() {
+    struct UseStateFunction {}
+    impl FunctionProvider for UseStateFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let renders = use_ref(|| 0);
+            *renders.borrow_mut() += 1;
+
+            let value = use_state(|| 0);
+            let value_clone = value.clone();
+            use_effect(move || {
+                value_clone.set_if_neq(1);
+                || {}
+            });
+            html! {
+                <div>
+                    {"Test Output: "}
+                    <div id="result">{*renders.borrow()}</div>
+                    {"\n"}
+                </div>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<UseStateFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "2");
+}
+
+#[wasm_bindgen_test]
+fn use_state_eq_skips_equal_values() {
+    struct UseStateEqFunction {}
+    impl FunctionProvider for UseStateEqFunction {
+        type TProps = ();
+
+        fn run(_: &Self::TProps) -> Html {
+            let renders = use_ref(|| 0);
+            *renders.borrow_mut() += 1;
+
+            let value = use_state_eq(|| "initial");
+            let value_clone = value.clone();
+            use_effect(move || {
+                value_clone.set("updated");
+                value_clone.update(|_| "updated");
+                || {}
+            });
+            html! {
+                <div>
+                    {"Test Output: "}
+                    <div id="result">{format!("{} {}", *value, *renders.borrow())}</div>
+                    {"\n"}
+                </div>
+            }
+        }
+    }
+    type UseComponent = FunctionComponent<UseStateEqFunction>;
+    yew::start_app_in_element::<UseComponent>(
+        yew::utils::document().get_element_by_id("output").unwrap(),
+    );
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "updated 2");
+}